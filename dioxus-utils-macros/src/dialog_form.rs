@@ -0,0 +1,152 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Fields, Ident, LitStr, Path};
+
+pub fn generate(input: &DeriveInput) -> Result<TokenStream, syn::Error> {
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let dto = read_dto(&input.attrs)?;
+    let fields = read_fields(input)?;
+
+    let is_any_updated = if fields.is_empty() {
+        quote! { false }
+    } else {
+        let checks = fields.iter().map(|field| {
+            quote! { self.#field.is_value_updated() }
+        });
+
+        quote! { #(#checks)||* }
+    };
+
+    let reset_to_init = fields.iter().map(|field| {
+        quote! { self.#field.init(self.#field.get_init_value().clone()); }
+    });
+
+    let get_updated_fields = fields.iter().map(|field| {
+        let name = field.to_string();
+        quote! {
+            if self.#field.is_value_updated() {
+                result.push(#name);
+            }
+        }
+    });
+
+    let dto_fns = match &dto {
+        Some(dto) => {
+            let init_from = fields.iter().map(|field| {
+                quote! { self.#field.init(dto.#field.clone()); }
+            });
+
+            let to_dto = fields.iter().map(|field| {
+                quote! { #field: self.#field.get_value().clone(), }
+            });
+
+            quote! {
+                pub fn init_from(&mut self, dto: &#dto) {
+                    #(#init_from)*
+                }
+
+                pub fn to_dto(&self) -> #dto {
+                    #dto {
+                        #(#to_dto)*
+                    }
+                }
+            }
+        }
+        None => quote! {},
+    };
+
+    let result = quote! {
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            pub fn is_any_updated(&self) -> bool {
+                #is_any_updated
+            }
+
+            pub fn reset_to_init(&mut self) {
+                #(#reset_to_init)*
+            }
+
+            pub fn get_updated_fields(&self) -> Vec<&'static str> {
+                let mut result = Vec::new();
+                #(#get_updated_fields)*
+                result
+            }
+
+            #dto_fns
+        }
+    };
+
+    Ok(result)
+}
+
+fn read_dto(attrs: &[Attribute]) -> Result<Option<Path>, syn::Error> {
+    let mut dto = None;
+
+    for attr in attrs {
+        if !attr.path().is_ident("dialog_form") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("dto") {
+                let value: LitStr = meta.value()?.parse()?;
+                dto = Some(value.parse::<Path>()?);
+                return Ok(());
+            }
+
+            Err(meta.error("unsupported dialog_form attribute. Expected dto = \"Type\""))
+        })?;
+    }
+
+    Ok(dto)
+}
+
+fn read_fields(input: &DeriveInput) -> Result<Vec<Ident>, syn::Error> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "DialogForm can only be derived for structs",
+        ));
+    };
+
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "DialogForm requires a struct with named fields",
+        ));
+    };
+
+    let mut result = Vec::new();
+
+    for field in &fields.named {
+        if is_skipped(&field.attrs)? {
+            continue;
+        }
+
+        result.push(field.ident.clone().unwrap());
+    }
+
+    Ok(result)
+}
+
+fn is_skipped(attrs: &[Attribute]) -> Result<bool, syn::Error> {
+    let mut skip = false;
+
+    for attr in attrs {
+        if !attr.path().is_ident("dialog_form") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skip = true;
+                return Ok(());
+            }
+
+            Err(meta.error("unsupported dialog_form field attribute. Expected skip"))
+        })?;
+    }
+
+    Ok(skip)
+}
@@ -0,0 +1,14 @@
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod dialog_form;
+
+#[proc_macro_derive(DialogForm, attributes(dialog_form))]
+pub fn dialog_form(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match dialog_form::generate(&input) {
+        Ok(result) => result.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
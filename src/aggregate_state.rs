@@ -0,0 +1,102 @@
+use std::fmt::Debug;
+
+use crate::{NotLoadedCases, RenderState};
+
+#[derive(Debug)]
+pub struct AggregateError<'s> {
+    pub name: &'static str,
+    pub error: &'s dyn Debug,
+}
+
+#[derive(Debug)]
+pub enum AggregateState<'s, TValues> {
+    Loaded(TValues),
+    NotLoaded(NotLoadedCases),
+    Errors(Vec<AggregateError<'s>>),
+}
+
+impl<'s, TValues> AggregateState<'s, TValues> {
+    pub fn is_loaded(&self) -> bool {
+        matches!(self, Self::Loaded(_))
+    }
+
+    pub fn is_error(&self) -> bool {
+        matches!(self, Self::Errors(_))
+    }
+
+    pub fn try_unwrap_as_loaded(&self) -> Option<&TValues> {
+        match self {
+            Self::Loaded(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub fn get_pending_names(&self) -> &[&'static str] {
+        match self {
+            Self::NotLoaded(NotLoadedCases::Pending(names)) => names,
+            _ => &[],
+        }
+    }
+
+    pub fn get_errors(&self) -> &[AggregateError<'s>] {
+        match self {
+            Self::Errors(errors) => errors,
+            _ => &[],
+        }
+    }
+}
+
+macro_rules! impl_aggregate {
+    ($fn_name:ident, $(($state:ident, $t:ident, $e:ident)),+) => {
+        pub fn $fn_name<'s, $($t: Debug, $e: Debug),+>(
+            $($state: (&'static str, &'s RenderState<$t, $e>)),+
+        ) -> AggregateState<'s, ($(&'s $t,)+)> {
+            let mut pending = Vec::new();
+            let mut errors = Vec::new();
+
+            $(
+                match $state.1 {
+                    RenderState::Loaded(_) => {}
+                    RenderState::Error(err) => errors.push(AggregateError {
+                        name: $state.0,
+                        error: err,
+                    }),
+                    _ => pending.push($state.0),
+                }
+            )+
+
+            if !errors.is_empty() {
+                return AggregateState::Errors(errors);
+            }
+
+            if !pending.is_empty() {
+                return AggregateState::NotLoaded(NotLoadedCases::Pending(pending));
+            }
+
+            AggregateState::Loaded(($($state.1.unwrap_as_loaded(),)+))
+        }
+    };
+}
+
+impl_aggregate!(aggregate2, (state1, T1, E1), (state2, T2, E2));
+impl_aggregate!(
+    aggregate3,
+    (state1, T1, E1),
+    (state2, T2, E2),
+    (state3, T3, E3)
+);
+impl_aggregate!(
+    aggregate4,
+    (state1, T1, E1),
+    (state2, T2, E2),
+    (state3, T3, E3),
+    (state4, T4, E4)
+);
+impl_aggregate!(
+    aggregate5,
+    (state1, T1, E1),
+    (state2, T2, E2),
+    (state3, T3, E3),
+    (state4, T4, E4),
+    (state5, T5, E5)
+);
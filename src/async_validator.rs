@@ -0,0 +1,84 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AsyncValidationStatus {
+    NotValidated,
+    Validating,
+    Valid,
+    Invalid(String),
+}
+
+impl AsyncValidationStatus {
+    pub fn is_validating(&self) -> bool {
+        matches!(self, Self::Validating)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid)
+    }
+
+    pub fn is_invalid(&self) -> bool {
+        matches!(self, Self::Invalid(_))
+    }
+
+    pub fn get_error(&self) -> Option<&str> {
+        match self {
+            Self::Invalid(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(any(feature = "fullstack", feature = "web"))]
+pub fn use_async_validator<T, TFuture>(
+    field: dioxus::prelude::Signal<crate::DialogValue<T>>,
+    debounce: std::time::Duration,
+    validator: impl Fn(T) -> TFuture + 'static,
+) -> dioxus::prelude::Signal<AsyncValidationStatus>
+where
+    T: Clone + Eq + Default + 'static,
+    TFuture: std::future::Future<Output = Option<String>> + 'static,
+{
+    use std::rc::Rc;
+
+    use dioxus::{core::Task, prelude::*};
+
+    let mut status = use_signal(|| AsyncValidationStatus::NotValidated);
+    let mut validated_value: CopyValue<Option<T>> = use_hook(|| CopyValue::new(None));
+    let mut validation_task: CopyValue<Option<Task>> = use_hook(|| CopyValue::new(None));
+    let validator = use_hook(|| Rc::new(validator));
+
+    use_effect(move || {
+        let value = field.read().get_value().clone();
+
+        if validated_value.peek().as_ref() == Some(&value) {
+            return;
+        }
+
+        validated_value.set(Some(value.clone()));
+
+        if let Some(task) = validation_task.write().take() {
+            task.cancel();
+        }
+
+        if !field.peek().is_value_updated() {
+            status.set(AsyncValidationStatus::NotValidated);
+            return;
+        }
+
+        status.set(AsyncValidationStatus::Validating);
+
+        let validator = validator.clone();
+
+        let task = spawn(async move {
+            crate::js::sleep(debounce).await;
+
+            match validator(value).await {
+                Some(err) => status.set(AsyncValidationStatus::Invalid(err)),
+                None => status.set(AsyncValidationStatus::Valid),
+            }
+        });
+
+        validation_task.set(Some(task));
+    });
+
+    status
+}
@@ -0,0 +1,134 @@
+use std::{future::Future, pin::Pin, rc::Rc};
+
+use dioxus::prelude::*;
+
+type RequestHandler<TRequest, TResponse> =
+    Rc<dyn Fn(TRequest) -> Pin<Box<dyn Future<Output = TResponse>>>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChildRequestError {
+    NoHandler,
+    Timeout,
+}
+
+struct ChildRequestChannelInner<TRequest, TResponse> {
+    // Registration order: the last handler answers, removing it falls back to the previous one
+    handlers: Vec<(u64, RequestHandler<TRequest, TResponse>)>,
+    next_handler_id: u64,
+}
+
+pub struct ChildRequestChannel<TRequest: 'static, TResponse: 'static> {
+    inner: CopyValue<ChildRequestChannelInner<TRequest, TResponse>>,
+}
+
+impl<TRequest: 'static, TResponse: 'static> Clone for ChildRequestChannel<TRequest, TResponse> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<TRequest: 'static, TResponse: 'static> Copy for ChildRequestChannel<TRequest, TResponse> {}
+
+impl<TRequest: 'static, TResponse: 'static> PartialEq for ChildRequestChannel<TRequest, TResponse> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<TRequest: 'static, TResponse: 'static> ChildRequestChannel<TRequest, TResponse> {
+    // Not `Default`: `new` is a hook and must only be called from a component body
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            inner: use_hook(|| {
+                CopyValue::new(ChildRequestChannelInner {
+                    handlers: Vec::new(),
+                    next_handler_id: 0,
+                })
+            }),
+        }
+    }
+
+    pub fn has_handler(&self) -> bool {
+        !self.inner.read().handlers.is_empty()
+    }
+
+    pub async fn request(&self, request: TRequest) -> Result<TResponse, ChildRequestError> {
+        let handler = match self.inner.read().handlers.last() {
+            Some((_, handler)) => handler.clone(),
+            None => return Err(ChildRequestError::NoHandler),
+        };
+
+        Ok(handler(request).await)
+    }
+
+    #[cfg(any(feature = "fullstack", feature = "web"))]
+    pub async fn request_with_timeout(
+        &self,
+        request: TRequest,
+        timeout: std::time::Duration,
+    ) -> Result<TResponse, ChildRequestError> {
+        let mut response = std::pin::pin!(self.request(request));
+        let mut timeout = std::pin::pin!(crate::js::sleep(timeout));
+
+        std::future::poll_fn(|cx| {
+            if let std::task::Poll::Ready(result) = response.as_mut().poll(cx) {
+                return std::task::Poll::Ready(result);
+            }
+
+            if timeout.as_mut().poll(cx).is_ready() {
+                return std::task::Poll::Ready(Err(ChildRequestError::Timeout));
+            }
+
+            std::task::Poll::Pending
+        })
+        .await
+    }
+
+    pub fn on_request<F>(&self, handler: F)
+    where
+        F: Fn(TRequest) -> TResponse + 'static,
+    {
+        self.on_request_async(move |request| std::future::ready(handler(request)));
+    }
+
+    pub fn on_request_async<F, TFuture>(&self, handler: F)
+    where
+        F: Fn(TRequest) -> TFuture + 'static,
+        TFuture: Future<Output = TResponse> + 'static,
+    {
+        let channel = *self;
+        let id = use_hook(|| channel.next_handler_id());
+
+        use_drop(move || channel.remove_handler(id));
+
+        let handler: RequestHandler<TRequest, TResponse> =
+            Rc::new(move |request| Box::pin(handler(request)));
+
+        let mut inner = self.inner;
+        let mut inner = inner.write();
+
+        match inner
+            .handlers
+            .iter_mut()
+            .find(|(handler_id, _)| *handler_id == id)
+        {
+            Some((_, current)) => *current = handler,
+            None => inner.handlers.push((id, handler)),
+        }
+    }
+
+    fn next_handler_id(&self) -> u64 {
+        let mut inner = self.inner;
+        let mut inner = inner.write();
+        inner.next_handler_id += 1;
+        inner.next_handler_id
+    }
+
+    fn remove_handler(&self, id: u64) {
+        let mut inner = self.inner;
+        let mut inner = inner.write();
+
+        inner.handlers.retain(|(handler_id, _)| *handler_id != id);
+    }
+}
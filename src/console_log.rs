@@ -21,7 +21,7 @@ pub fn console_log<'s>(message: impl Into<StrOrString<'s>>) {
 }
 
 #[cfg(not(feature = "server"))]
-fn escape_for_java_script_string(message: &str) -> String {
+pub(crate) fn escape_for_java_script_string(message: &str) -> String {
     let mut result = String::with_capacity(message.len());
 
     for ch in message.chars() {
@@ -1,49 +1,312 @@
 use core::panic;
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
-use crate::RenderState;
+use dioxus::core::Task;
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use crate::{LoadingProgress, RenderState, RetryAttempt};
 
 #[derive(Debug)]
-pub struct DataState<T: Debug> {
-    inner: RenderState<T>,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: serde::Serialize, E: serde::Serialize",
+        deserialize = "T: serde::Deserialize<'de>, E: serde::Deserialize<'de>"
+    ))
+)]
+pub struct DataState<T: Debug, E: Debug = String> {
+    inner: RenderState<T, E>,
     pub had_data_loaded_once: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    refreshing: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    refresh_error: Option<E>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    retry_attempt: Option<RetryAttempt>,
+    #[cfg_attr(feature = "serde", serde(default, with = "loaded_at_serde"))]
+    loaded_at: Option<DateTimeAsMicroseconds>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    ttl: Option<Duration>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    mutation_error: Option<E>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    progress: Option<LoadingProgress>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    generation: u64,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    value_version: u64,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    load_task: Option<Task>,
 }
 
-impl<T: Debug> DataState<T> {
-    pub fn new() -> Self {
+impl<T: Debug, E: Debug> DataState<T, E> {
+    pub fn new_typed() -> Self {
         Self {
             inner: RenderState::None,
             had_data_loaded_once: false,
+            refreshing: false,
+            refresh_error: None,
+            retry_attempt: None,
+            loaded_at: None,
+            ttl: None,
+            mutation_error: None,
+            progress: None,
+            generation: 0,
+            value_version: 0,
+            load_task: None,
         }
     }
 
-    pub fn new_as_loaded(value: T) -> Self {
+    pub fn new_typed_as_loaded(value: T) -> Self {
         Self {
             inner: RenderState::Loaded(value),
             had_data_loaded_once: true,
+            refreshing: false,
+            refresh_error: None,
+            retry_attempt: None,
+            loaded_at: Some(crate::now_timestamp()),
+            ttl: None,
+            mutation_error: None,
+            progress: None,
+            generation: 0,
+            value_version: 0,
+            load_task: None,
         }
     }
 
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
     pub fn is_none(&self) -> bool {
         self.inner.is_none()
     }
 
     pub fn set_loading(&mut self) {
+        self.next_generation();
+        self.clear_load_status();
+        self.loaded_at = None;
         self.inner.set_loading();
     }
 
+    pub fn set_refreshing(&mut self) {
+        if !self.inner.has_value() {
+            self.set_loading();
+            return;
+        }
+
+        self.next_generation();
+        self.refreshing = true;
+        self.refresh_error = None;
+        self.progress = None;
+    }
+
     pub fn set_loaded(&mut self, value: T) {
+        self.next_value_version();
+        self.clear_load_status();
+        self.loaded_at = Some(crate::now_timestamp());
         self.inner.set_loaded(value);
     }
 
-    pub fn set_error(&mut self, err: impl Debug) {
-        self.inner.set_error(err);
+    pub fn set_typed_error(&mut self, err: E) {
+        self.clear_load_status();
+        self.loaded_at = None;
+        self.inner.set_typed_error(err);
+    }
+
+    pub fn set_typed_refresh_error(&mut self, err: E) {
+        if !self.inner.has_value() {
+            self.set_typed_error(err);
+            return;
+        }
+
+        self.clear_load_status();
+        self.refresh_error = Some(err);
     }
 
     pub fn reset(&mut self) {
+        self.next_generation();
+        self.clear_load_status();
+        self.loaded_at = None;
         self.inner = RenderState::None;
     }
 
+    pub fn start_loading(&mut self) -> u64 {
+        self.set_loading();
+        self.generation
+    }
+
+    pub fn start_refreshing(&mut self) -> u64 {
+        self.set_refreshing();
+        self.generation
+    }
+
+    pub fn get_generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn is_current_generation(&self, generation: u64) -> bool {
+        self.generation == generation
+    }
+
+    pub fn attach_task(&mut self, generation: u64, task: Task) {
+        if !self.is_current_generation(generation) {
+            task.cancel();
+            return;
+        }
+
+        if let Some(prev_task) = self.load_task.replace(task) {
+            if prev_task != task {
+                prev_task.cancel();
+            }
+        }
+    }
+
+    pub fn set_loaded_if_current(&mut self, generation: u64, value: T) -> bool {
+        if !self.is_current_generation(generation) {
+            return false;
+        }
+
+        self.load_task = None;
+        self.set_value(value);
+        true
+    }
+
+    pub fn set_typed_error_if_current(&mut self, generation: u64, err: E) -> bool {
+        if !self.is_current_generation(generation) {
+            return false;
+        }
+
+        self.load_task = None;
+        self.set_typed_error(err);
+        true
+    }
+
+    pub fn set_typed_refresh_error_if_current(&mut self, generation: u64, err: E) -> bool {
+        if !self.is_current_generation(generation) {
+            return false;
+        }
+
+        self.load_task = None;
+        self.set_typed_refresh_error(err);
+        true
+    }
+
+    fn next_value_version(&mut self) {
+        self.value_version += 1;
+    }
+
+    fn next_generation(&mut self) {
+        self.generation += 1;
+
+        if let Some(task) = self.load_task.take() {
+            task.cancel();
+        }
+    }
+
+    pub fn is_refreshing(&self) -> bool {
+        self.refreshing
+    }
+
+    pub fn get_refresh_error(&self) -> Option<&E> {
+        self.refresh_error.as_ref()
+    }
+
+    pub fn set_retry_attempt(&mut self, retry_attempt: RetryAttempt) {
+        self.retry_attempt = Some(retry_attempt);
+    }
+
+    pub fn get_retry_attempt(&self) -> Option<&RetryAttempt> {
+        self.retry_attempt.as_ref()
+    }
+
+    pub fn set_progress_fraction(&mut self, fraction: f64) {
+        if let Some(progress) = self.get_progress_mut() {
+            progress.fraction = Some(fraction);
+        }
+    }
+
+    pub fn set_progress_bytes(&mut self, done: u64, total: u64) {
+        if let Some(progress) = self.get_progress_mut() {
+            progress.bytes = Some((done, total));
+        }
+    }
+
+    pub fn set_progress_step(&mut self, step: impl Into<String>) {
+        if let Some(progress) = self.get_progress_mut() {
+            progress.step = Some(step.into());
+        }
+    }
+
+    pub fn get_progress(&self) -> Option<&LoadingProgress> {
+        self.progress.as_ref()
+    }
+
+    fn get_progress_mut(&mut self) -> Option<&mut LoadingProgress> {
+        if !self.inner.is_loading() && !self.refreshing {
+            return None;
+        }
+
+        Some(self.progress.get_or_insert_with(LoadingProgress::default))
+    }
+
+    pub fn set_ttl(&mut self, ttl: Option<Duration>) {
+        self.ttl = ttl;
+    }
+
+    pub fn get_ttl(&self) -> Option<Duration> {
+        self.ttl
+    }
+
+    pub fn get_loaded_at(&self) -> Option<DateTimeAsMicroseconds> {
+        self.loaded_at
+    }
+
+    pub fn get_loaded_age(&self) -> Option<Duration> {
+        let loaded_at = self.loaded_at?;
+        let age = crate::now_timestamp().unix_microseconds - loaded_at.unix_microseconds;
+        Some(Duration::from_micros(age.max(0) as u64))
+    }
+
+    pub fn is_stale(&self, ttl: Duration) -> bool {
+        match self.get_loaded_age() {
+            Some(age) => age >= ttl,
+            None => false,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        match self.ttl {
+            Some(ttl) => self.is_stale(ttl),
+            None => false,
+        }
+    }
+
+    pub fn get_time_to_expire(&self) -> Option<Duration> {
+        let ttl = self.ttl?;
+        let age = self.get_loaded_age()?;
+        Some(ttl.saturating_sub(age))
+    }
+
+    pub fn invalidate_if_expired(&mut self) -> bool {
+        if !self.is_expired() {
+            return false;
+        }
+
+        self.reset();
+        true
+    }
+
+    fn clear_load_status(&mut self) {
+        self.refreshing = false;
+        self.refresh_error = None;
+        self.retry_attempt = None;
+        self.mutation_error = None;
+        self.progress = None;
+    }
+
     pub fn is_loading(&self) -> bool {
         self.inner.is_loading()
     }
@@ -52,6 +315,10 @@ impl<T: Debug> DataState<T> {
         self.inner.has_value()
     }
 
+    pub fn is_error(&self) -> bool {
+        self.inner.is_error()
+    }
+
     pub fn try_unwrap_as_loaded(&self) -> Option<&T> {
         match &self.inner {
             RenderState::Loaded(value) => value.into(),
@@ -66,6 +333,10 @@ impl<T: Debug> DataState<T> {
         }
     }
 
+    pub fn try_unwrap_as_error(&self) -> Option<&E> {
+        self.inner.try_unwrap_as_error()
+    }
+
     pub fn to_not_loaded_cases(&self) -> Option<NotLoadedCases> {
         match &self.inner {
             RenderState::None => NotLoadedCases::None.into(),
@@ -76,6 +347,9 @@ impl<T: Debug> DataState<T> {
     }
 
     pub fn set_value(&mut self, value: T) {
+        self.next_value_version();
+        self.clear_load_status();
+        self.loaded_at = Some(crate::now_timestamp());
         self.inner = RenderState::Loaded(value);
         self.had_data_loaded_once = true;
     }
@@ -107,14 +381,103 @@ impl<T: Debug> DataState<T> {
         }
     }
 
-    pub fn as_ref(&self) -> &RenderState<T> {
+    pub fn as_ref(&self) -> &RenderState<T, E> {
         &self.inner
     }
 }
 
-impl<T: Debug> Default for DataState<T> {
+impl<T: Debug + Clone, E: Debug> DataState<T, E> {
+    pub fn apply_optimistic(&mut self, update: impl FnOnce(&mut T)) -> Option<OptimisticUpdate<T>> {
+        let value = self.try_unwrap_as_loaded_mut()?;
+        let previous = value.clone();
+        update(value);
+
+        self.next_value_version();
+        self.mutation_error = None;
+        Some(OptimisticUpdate {
+            previous,
+            value_version: self.value_version,
+        })
+    }
+
+    pub fn confirm_optimistic(&mut self, _update: OptimisticUpdate<T>) {
+        self.mutation_error = None;
+    }
+
+    pub fn rollback_optimistic_typed(&mut self, update: OptimisticUpdate<T>, err: E) -> bool {
+        self.mutation_error = Some(err);
+
+        if self.value_version != update.value_version {
+            return false;
+        }
+
+        match self.try_unwrap_as_loaded_mut() {
+            Some(value) => {
+                *value = update.previous;
+                self.next_value_version();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn get_mutation_error(&self) -> Option<&E> {
+        self.mutation_error.as_ref()
+    }
+}
+
+impl<T: Debug + Clone> DataState<T, String> {
+    pub fn rollback_optimistic(&mut self, update: OptimisticUpdate<T>, err: impl Debug) -> bool {
+        self.rollback_optimistic_typed(update, format!("{:?}", err))
+    }
+}
+
+impl<T: Debug> DataState<T, String> {
+    pub fn new() -> Self {
+        Self::new_typed()
+    }
+
+    pub fn new_as_loaded(value: T) -> Self {
+        Self::new_typed_as_loaded(value)
+    }
+
+    pub fn set_error(&mut self, err: impl Debug) {
+        self.set_typed_error(format!("{:?}", err));
+    }
+
+    pub fn set_error_if_current(&mut self, generation: u64, err: impl Debug) -> bool {
+        self.set_typed_error_if_current(generation, format!("{:?}", err))
+    }
+
+    pub fn set_refresh_error(&mut self, err: impl Debug) {
+        self.set_typed_refresh_error(format!("{:?}", err));
+    }
+
+    pub fn set_refresh_error_if_current(&mut self, generation: u64, err: impl Debug) -> bool {
+        self.set_typed_refresh_error_if_current(generation, format!("{:?}", err))
+    }
+}
+
+impl<T: Debug, E: Debug> Default for DataState<T, E> {
     fn default() -> Self {
-        Self::new()
+        Self::new_typed()
+    }
+}
+
+#[must_use]
+#[derive(Debug)]
+pub struct OptimisticUpdate<T> {
+    previous: T,
+    value_version: u64,
+}
+
+impl<T> OptimisticUpdate<T> {
+    pub fn get_previous_value(&self) -> &T {
+        &self.previous
+    }
+
+    pub fn get_value_version(&self) -> u64 {
+        self.value_version
     }
 }
 
@@ -122,4 +485,27 @@ impl<T: Debug> Default for DataState<T> {
 pub enum NotLoadedCases {
     None,
     Loading,
+    Pending(Vec<&'static str>),
+}
+
+#[cfg(feature = "serde")]
+mod loaded_at_serde {
+    use rust_extensions::date_time::DateTimeAsMicroseconds;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<DateTimeAsMicroseconds>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value
+            .map(|value| value.unix_microseconds)
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<DateTimeAsMicroseconds>, D::Error> {
+        let value = Option::<i64>::deserialize(deserializer)?;
+        Ok(value.map(DateTimeAsMicroseconds::new))
+    }
 }
@@ -2,15 +2,16 @@ use core::panic;
 use std::fmt::Debug;
 
 #[derive(Debug)]
-pub enum RenderState<T: Debug> {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RenderState<T: Debug, E: Debug = String> {
     None,
     Loading,
     Loaded(T),
-    Error(String),
+    Error(E),
 }
 
-impl<T: Debug> RenderState<T> {
-    pub fn new() -> Self {
+impl<T: Debug, E: Debug> RenderState<T, E> {
+    pub fn new_typed() -> Self {
         RenderState::None
     }
 
@@ -29,8 +30,8 @@ impl<T: Debug> RenderState<T> {
         *self = Self::Loaded(value);
     }
 
-    pub fn set_error(&mut self, err: impl Debug) {
-        *self = Self::Error(format!("{:?}", err));
+    pub fn set_typed_error(&mut self, err: E) {
+        *self = Self::Error(err);
     }
 
     pub fn set_none(&mut self) {
@@ -51,6 +52,13 @@ impl<T: Debug> RenderState<T> {
         }
     }
 
+    pub fn is_error(&self) -> bool {
+        match self {
+            Self::Error(_) => true,
+            _ => false,
+        }
+    }
+
     pub fn try_unwrap_as_loaded(&self) -> Option<&T> {
         match self {
             Self::Loaded(value) => value.into(),
@@ -65,6 +73,13 @@ impl<T: Debug> RenderState<T> {
         }
     }
 
+    pub fn try_unwrap_as_error(&self) -> Option<&E> {
+        match self {
+            Self::Error(err) => Some(err),
+            _ => None,
+        }
+    }
+
     pub fn set_value(&mut self, value: T) {
         *self = Self::Loaded(value);
     }
@@ -91,4 +106,90 @@ impl<T: Debug> RenderState<T> {
     pub fn as_ref(&self) -> &Self {
         self
     }
+
+    pub fn as_value_ref(&self) -> RenderState<&T, &E> {
+        match self {
+            Self::None => RenderState::None,
+            Self::Loading => RenderState::Loading,
+            Self::Loaded(value) => RenderState::Loaded(value),
+            Self::Error(err) => RenderState::Error(err),
+        }
+    }
+
+    pub fn as_value_mut(&mut self) -> RenderState<&mut T, &mut E> {
+        match self {
+            Self::None => RenderState::None,
+            Self::Loading => RenderState::Loading,
+            Self::Loaded(value) => RenderState::Loaded(value),
+            Self::Error(err) => RenderState::Error(err),
+        }
+    }
+
+    pub fn map<U: Debug>(self, f: impl FnOnce(T) -> U) -> RenderState<U, E> {
+        match self {
+            Self::None => RenderState::None,
+            Self::Loading => RenderState::Loading,
+            Self::Loaded(value) => RenderState::Loaded(f(value)),
+            Self::Error(err) => RenderState::Error(err),
+        }
+    }
+
+    pub fn map_err<F: Debug>(self, f: impl FnOnce(E) -> F) -> RenderState<T, F> {
+        match self {
+            Self::None => RenderState::None,
+            Self::Loading => RenderState::Loading,
+            Self::Loaded(value) => RenderState::Loaded(value),
+            Self::Error(err) => RenderState::Error(f(err)),
+        }
+    }
+
+    pub fn and_then<U: Debug>(self, f: impl FnOnce(T) -> RenderState<U, E>) -> RenderState<U, E> {
+        match self {
+            Self::None => RenderState::None,
+            Self::Loading => RenderState::Loading,
+            Self::Loaded(value) => f(value),
+            Self::Error(err) => RenderState::Error(err),
+        }
+    }
+
+    pub fn zip<U: Debug>(self, other: RenderState<U, E>) -> RenderState<(T, U), E> {
+        match (self, other) {
+            (Self::Loaded(value), RenderState::Loaded(other_value)) => {
+                RenderState::Loaded((value, other_value))
+            }
+            (Self::Error(err), _) | (_, RenderState::Error(err)) => RenderState::Error(err),
+            (Self::Loading, _) | (_, RenderState::Loading) => RenderState::Loading,
+            _ => RenderState::None,
+        }
+    }
+
+    pub fn unwrap_or(self, default: T) -> T {
+        match self {
+            Self::Loaded(value) => value,
+            _ => default,
+        }
+    }
+
+    pub fn unwrap_or_else(self, f: impl FnOnce() -> T) -> T {
+        match self {
+            Self::Loaded(value) => value,
+            _ => f(),
+        }
+    }
+}
+
+impl<T: Debug> RenderState<T, String> {
+    pub fn new() -> Self {
+        Self::new_typed()
+    }
+
+    pub fn set_error(&mut self, err: impl Debug) {
+        *self = Self::Error(format!("{:?}", err));
+    }
+}
+
+impl<T: Debug, E: Debug> Default for RenderState<T, E> {
+    fn default() -> Self {
+        Self::new_typed()
+    }
 }
@@ -0,0 +1,156 @@
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::DialogValue;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DialogValueChange {
+    pub path: String,
+    pub old_value: Option<Value>,
+    pub new_value: Option<Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DialogValueDiff {
+    pub changes: Vec<DialogValueChange>,
+}
+
+impl DialogValueDiff {
+    pub fn new(old_value: &Value, new_value: &Value) -> Self {
+        let mut changes = Vec::new();
+        collect_changes(&mut changes, String::new(), old_value, new_value);
+        Self { changes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn get_changed_paths(&self) -> Vec<&str> {
+        self.changes
+            .iter()
+            .map(|change| change.path.as_str())
+            .collect()
+    }
+
+    pub fn to_merge_patch(&self) -> Value {
+        let mut result = Value::Object(Default::default());
+
+        for change in &self.changes {
+            let new_value = change.new_value.clone().unwrap_or(Value::Null);
+
+            if change.path.is_empty() {
+                return new_value;
+            }
+
+            let segments: Vec<String> = change.path[1..].split('/').map(unescape).collect();
+            let (last, parents) = segments.split_last().unwrap();
+
+            let mut target = &mut result;
+
+            for segment in parents {
+                target = target
+                    .as_object_mut()
+                    .unwrap()
+                    .entry(segment.as_str())
+                    .or_insert_with(|| Value::Object(Default::default()));
+            }
+
+            target
+                .as_object_mut()
+                .unwrap()
+                .insert(last.to_string(), new_value);
+        }
+
+        result
+    }
+
+    pub fn to_json_patch(&self) -> Value {
+        let operations = self
+            .changes
+            .iter()
+            .map(|change| match (&change.old_value, &change.new_value) {
+                (None, Some(value)) => {
+                    serde_json::json!({ "op": "add", "path": change.path, "value": value })
+                }
+                (Some(_), None) => serde_json::json!({ "op": "remove", "path": change.path }),
+                (_, value) => {
+                    serde_json::json!({ "op": "replace", "path": change.path, "value": value })
+                }
+            })
+            .collect();
+
+        Value::Array(operations)
+    }
+
+    #[cfg(feature = "web")]
+    pub fn to_merge_patch_body(&self) -> crate::js::fl_url::FlUrlBody {
+        crate::js::fl_url::FlUrlBody::as_merge_patch(&self.to_merge_patch())
+    }
+
+    #[cfg(feature = "web")]
+    pub fn to_json_patch_body(&self) -> crate::js::fl_url::FlUrlBody {
+        crate::js::fl_url::FlUrlBody::as_json_patch(&self.to_json_patch())
+    }
+}
+
+impl<T: Clone + Eq + Default + Serialize> DialogValue<T> {
+    pub fn get_diff(&self) -> Result<DialogValueDiff, serde_json::Error> {
+        let old_value = serde_json::to_value(self.get_init_value())?;
+        let new_value = serde_json::to_value(self.get_value())?;
+        Ok(DialogValueDiff::new(&old_value, &new_value))
+    }
+}
+
+fn collect_changes(
+    changes: &mut Vec<DialogValueChange>,
+    path: String,
+    old_value: &Value,
+    new_value: &Value,
+) {
+    if old_value == new_value {
+        return;
+    }
+
+    let (Value::Object(old_fields), Value::Object(new_fields)) = (old_value, new_value) else {
+        changes.push(DialogValueChange {
+            path,
+            old_value: Some(old_value.clone()),
+            new_value: Some(new_value.clone()),
+        });
+        return;
+    };
+
+    for (name, old_field) in old_fields {
+        let field_path = format!("{}/{}", path, escape(name));
+
+        match new_fields.get(name) {
+            Some(new_field) => collect_changes(changes, field_path, old_field, new_field),
+            None => changes.push(DialogValueChange {
+                path: field_path,
+                old_value: Some(old_field.clone()),
+                new_value: None,
+            }),
+        }
+    }
+
+    for (name, new_field) in new_fields {
+        if old_fields.contains_key(name) {
+            continue;
+        }
+
+        changes.push(DialogValueChange {
+            path: format!("{}/{}", path, escape(name)),
+            old_value: None,
+            new_value: Some(new_field.clone()),
+        });
+    }
+}
+
+fn escape(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+fn unescape(segment: &str) -> String {
+    segment.replace("~1", "/").replace("~0", "~")
+}
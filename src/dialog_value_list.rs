@@ -0,0 +1,175 @@
+use std::{collections::HashMap, hash::Hash};
+
+#[derive(Debug)]
+pub struct DialogValueListChanges<'s, T> {
+    pub added: Vec<&'s T>,
+    pub removed: Vec<&'s T>,
+    pub modified: Vec<(&'s T, &'s T)>,
+    pub reordered: bool,
+}
+
+impl<'s, T> DialogValueListChanges<'s, T> {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.modified.is_empty()
+            && !self.reordered
+    }
+}
+
+#[derive(Debug)]
+pub struct DialogValueList<T: Clone + Eq, TKey: Eq + Hash> {
+    init_value: Vec<T>,
+    value: Vec<T>,
+    get_key: fn(&T) -> TKey,
+}
+
+impl<T: Clone + Eq, TKey: Eq + Hash> DialogValueList<T, TKey> {
+    pub fn new(value: Vec<T>, get_key: fn(&T) -> TKey) -> Self {
+        Self {
+            init_value: value.clone(),
+            value,
+            get_key,
+        }
+    }
+
+    pub fn init(&mut self, value: Vec<T>) {
+        self.init_value = value.clone();
+        self.value = value;
+    }
+
+    pub fn set_value(&mut self, value: Vec<T>) {
+        self.value = value;
+    }
+
+    pub fn get_init_value(&self) -> &[T] {
+        &self.init_value
+    }
+
+    pub fn get_value(&self) -> &[T] {
+        &self.value
+    }
+
+    pub fn get_value_mut(&mut self) -> &mut Vec<T> {
+        &mut self.value
+    }
+
+    pub fn get_by_key(&self, key: &TKey) -> Option<&T> {
+        self.value.iter().find(|item| (self.get_key)(item) == *key)
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.value.push(item);
+    }
+
+    pub fn update_by_key(&mut self, key: &TKey, update: impl FnOnce(&mut T)) -> bool {
+        let get_key = self.get_key;
+
+        match self.value.iter_mut().find(|item| get_key(item) == *key) {
+            Some(item) => {
+                update(item);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn remove_by_key(&mut self, key: &TKey) -> Option<T> {
+        let index = self
+            .value
+            .iter()
+            .position(|item| (self.get_key)(item) == *key)?;
+
+        Some(self.value.remove(index))
+    }
+
+    pub fn move_item(&mut self, from: usize, to: usize) {
+        if from >= self.value.len() || to >= self.value.len() {
+            return;
+        }
+
+        let item = self.value.remove(from);
+        self.value.insert(to, item);
+    }
+
+    pub fn revert(&mut self) {
+        self.value = self.init_value.clone();
+    }
+
+    pub fn is_value_updated(&self) -> bool {
+        self.init_value != self.value
+    }
+
+    pub fn get_added(&self) -> Vec<&T> {
+        let init_keys = self.get_key_index(&self.init_value);
+
+        self.value
+            .iter()
+            .filter(|item| !init_keys.contains_key(&(self.get_key)(item)))
+            .collect()
+    }
+
+    pub fn get_removed(&self) -> Vec<&T> {
+        let keys = self.get_key_index(&self.value);
+
+        self.init_value
+            .iter()
+            .filter(|item| !keys.contains_key(&(self.get_key)(item)))
+            .collect()
+    }
+
+    pub fn get_modified(&self) -> Vec<(&T, &T)> {
+        let init_keys = self.get_key_index(&self.init_value);
+
+        self.value
+            .iter()
+            .filter_map(|item| {
+                let init_item = &self.init_value[*init_keys.get(&(self.get_key)(item))?];
+
+                if init_item == item {
+                    return None;
+                }
+
+                Some((init_item, item))
+            })
+            .collect()
+    }
+
+    pub fn is_reordered(&self) -> bool {
+        let keys = self.get_key_index(&self.value);
+
+        let kept_init_order = self
+            .init_value
+            .iter()
+            .filter_map(|item| keys.get(&(self.get_key)(item)).copied());
+
+        let mut last_index = None;
+
+        for index in kept_init_order {
+            if last_index.is_some_and(|last_index| index < last_index) {
+                return true;
+            }
+
+            last_index = Some(index);
+        }
+
+        false
+    }
+
+    pub fn get_changes(&self) -> DialogValueListChanges<'_, T> {
+        DialogValueListChanges {
+            added: self.get_added(),
+            removed: self.get_removed(),
+            modified: self.get_modified(),
+            reordered: self.is_reordered(),
+        }
+    }
+
+    fn get_key_index(&self, items: &[T]) -> HashMap<TKey, usize> {
+        items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| ((self.get_key)(item), index))
+            .collect()
+    }
+}
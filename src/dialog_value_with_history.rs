@@ -0,0 +1,170 @@
+use std::{collections::VecDeque, time::Duration};
+
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use crate::DialogValue;
+
+const DEFAULT_MAX_HISTORY: usize = 100;
+const DEFAULT_GROUP_WINDOW: Duration = Duration::from_millis(500);
+
+#[derive(Debug)]
+pub struct DialogValueWithHistory<T: Clone + Eq + Default> {
+    value: DialogValue<T>,
+    undo: VecDeque<T>,
+    redo: Vec<T>,
+    max_history: usize,
+    group_window: Duration,
+    last_change_at: Option<DateTimeAsMicroseconds>,
+}
+
+impl<T: Clone + Eq + Default> DialogValueWithHistory<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value: DialogValue::new(value),
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            max_history: DEFAULT_MAX_HISTORY,
+            group_window: DEFAULT_GROUP_WINDOW,
+            last_change_at: None,
+        }
+    }
+
+    pub fn with_max_history(mut self, max_history: usize) -> Self {
+        self.max_history = max_history;
+        self
+    }
+
+    pub fn with_group_window(mut self, group_window: Duration) -> Self {
+        self.group_window = group_window;
+        self
+    }
+
+    pub fn init(&mut self, value: T) {
+        self.value.init(value);
+        self.undo.clear();
+        self.redo.clear();
+        self.last_change_at = None;
+    }
+
+    pub fn set_value(&mut self, value: T) {
+        if &value == self.value.get_value() {
+            return;
+        }
+
+        let now = crate::now_timestamp();
+
+        if !self.is_in_group_window(now) {
+            self.push_undo(self.value.get_value().clone());
+        }
+
+        self.redo.clear();
+        self.value.set_value(value);
+        self.last_change_at = Some(now);
+    }
+
+    pub fn update(&mut self, update: impl FnOnce(&mut T)) {
+        let mut value = self.value.get_value().clone();
+        update(&mut value);
+        self.set_value(value);
+    }
+
+    pub fn commit_group(&mut self) {
+        self.last_change_at = None;
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    pub fn undo(&mut self) -> bool {
+        let Some(value) = self.undo.pop_back() else {
+            return false;
+        };
+
+        self.redo.push(self.value.get_value().clone());
+        self.value.set_value(value);
+        self.last_change_at = None;
+        true
+    }
+
+    pub fn redo(&mut self) -> bool {
+        let Some(value) = self.redo.pop() else {
+            return false;
+        };
+
+        self.push_undo(self.value.get_value().clone());
+        self.value.set_value(value);
+        self.last_change_at = None;
+        true
+    }
+
+    pub fn revert_to_init(&mut self) {
+        if !self.value.is_value_updated() {
+            return;
+        }
+
+        self.push_undo(self.value.get_value().clone());
+        self.redo.clear();
+        self.value.set_value(self.value.get_init_value().clone());
+        self.last_change_at = None;
+    }
+
+    pub fn get_init_value(&self) -> &T {
+        self.value.get_init_value()
+    }
+
+    pub fn get_value(&self) -> &T {
+        self.value.get_value()
+    }
+
+    pub fn is_value_updated(&self) -> bool {
+        self.value.is_value_updated()
+    }
+
+    pub fn as_dialog_value(&self) -> &DialogValue<T> {
+        &self.value
+    }
+
+    fn is_in_group_window(&self, now: DateTimeAsMicroseconds) -> bool {
+        let Some(last_change_at) = self.last_change_at else {
+            return false;
+        };
+
+        let elapsed = now.unix_microseconds - last_change_at.unix_microseconds;
+        Duration::from_micros(elapsed.max(0) as u64) <= self.group_window
+    }
+
+    fn push_undo(&mut self, value: T) {
+        if self.max_history == 0 {
+            return;
+        }
+
+        if self.undo.len() >= self.max_history {
+            self.undo.pop_front();
+        }
+
+        self.undo.push_back(value);
+    }
+}
+
+impl DialogValueWithHistory<String> {
+    pub fn as_str(&self) -> &str {
+        self.value.as_str()
+    }
+}
+
+impl<T: Clone + Eq + Default> Default for DialogValueWithHistory<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Clone + Eq + Default> From<T> for DialogValueWithHistory<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::{HashMap, VecDeque};
+
+use dioxus::prelude::*;
+
+struct EventBusSubscriber<T> {
+    next_seq: u64,
+    replay: Option<T>,
+}
+
+struct EventBusInner<T> {
+    events: VecDeque<(u64, T)>,
+    next_seq: u64,
+    last_event: Option<T>,
+    replay_last: bool,
+    subscribers: HashMap<u64, EventBusSubscriber<T>>,
+    next_subscriber_id: u64,
+}
+
+impl<T: Clone> EventBusInner<T> {
+    fn trim(&mut self) {
+        let min_seq = self
+            .subscribers
+            .values()
+            .map(|subscriber| subscriber.next_seq)
+            .min()
+            .unwrap_or(self.next_seq);
+
+        while let Some((seq, _)) = self.events.front() {
+            if *seq >= min_seq {
+                break;
+            }
+
+            self.events.pop_front();
+        }
+    }
+}
+
+pub struct EventBus<T: 'static> {
+    inner: CopyValue<EventBusInner<T>>,
+    version: Signal<u64>,
+}
+
+impl<T: 'static> Clone for EventBus<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for EventBus<T> {}
+
+impl<T: 'static> PartialEq for EventBus<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.version == other.version
+    }
+}
+
+impl<T: Clone + 'static> EventBus<T> {
+    fn new(replay_last: bool) -> Self {
+        Self {
+            inner: CopyValue::new(EventBusInner {
+                events: VecDeque::new(),
+                next_seq: 0,
+                last_event: None,
+                replay_last,
+                subscribers: HashMap::new(),
+                next_subscriber_id: 0,
+            }),
+            version: Signal::new(0),
+        }
+    }
+
+    pub fn publish(&self, event: T) {
+        let mut inner = self.inner;
+
+        {
+            let mut inner = inner.write();
+
+            if inner.replay_last {
+                inner.last_event = Some(event.clone());
+            }
+
+            if !inner.subscribers.is_empty() {
+                let seq = inner.next_seq;
+                inner.events.push_back((seq, event));
+            }
+
+            inner.next_seq += 1;
+        }
+
+        let mut version = self.version;
+        *version.write() += 1;
+    }
+
+    pub fn get_last_event(&self) -> Option<T> {
+        self.inner.read().last_event.clone()
+    }
+
+    pub fn get_subscribers_count(&self) -> usize {
+        self.inner.read().subscribers.len()
+    }
+
+    pub fn on_event<F>(&self, mut callback: F)
+    where
+        F: FnMut(T) + 'static,
+    {
+        let bus = *self;
+        let id = use_hook(|| bus.subscribe());
+
+        use_drop(move || bus.unsubscribe(id));
+
+        use_effect(move || {
+            let _ = bus.version.read();
+
+            for event in bus.take_pending(id) {
+                callback(event);
+            }
+        });
+    }
+
+    fn subscribe(&self) -> u64 {
+        let mut inner = self.inner;
+        let mut inner = inner.write();
+
+        let id = inner.next_subscriber_id;
+        inner.next_subscriber_id += 1;
+
+        let subscriber = EventBusSubscriber {
+            next_seq: inner.next_seq,
+            replay: inner.last_event.clone(),
+        };
+
+        inner.subscribers.insert(id, subscriber);
+
+        id
+    }
+
+    fn unsubscribe(&self, id: u64) {
+        let mut inner = self.inner;
+        let mut inner = inner.write();
+
+        inner.subscribers.remove(&id);
+        inner.trim();
+    }
+
+    fn take_pending(&self, id: u64) -> Vec<T> {
+        let mut inner = self.inner;
+        let mut inner = inner.write();
+        let inner = &mut *inner;
+
+        let Some(subscriber) = inner.subscribers.get_mut(&id) else {
+            return Vec::new();
+        };
+
+        let mut result: Vec<T> = subscriber.replay.take().into_iter().collect();
+
+        for (seq, event) in &inner.events {
+            if *seq >= subscriber.next_seq {
+                result.push(event.clone());
+            }
+        }
+
+        subscriber.next_seq = inner.next_seq;
+        inner.trim();
+
+        result
+    }
+}
+
+pub fn use_event_bus_provider<T: Clone + 'static>(replay_last: bool) -> EventBus<T> {
+    use_context_provider(|| EventBus::new(replay_last))
+}
+
+pub fn use_event_bus<T: Clone + 'static>() -> EventBus<T> {
+    use_context()
+}
@@ -0,0 +1,243 @@
+use std::rc::Rc;
+
+use crate::DialogValue;
+
+type ValidateFn<T> = dyn Fn(&T) -> Option<String>;
+
+pub struct FieldValidator<T> {
+    validate: Rc<ValidateFn<T>>,
+}
+
+impl<T> FieldValidator<T> {
+    pub fn custom(validate: impl Fn(&T) -> Option<String> + 'static) -> Self {
+        Self {
+            validate: Rc::new(validate),
+        }
+    }
+
+    pub fn validate(&self, value: &T) -> Option<String> {
+        (self.validate)(value)
+    }
+}
+
+impl FieldValidator<String> {
+    pub fn required(message: impl Into<String>) -> Self {
+        let message = message.into();
+        Self::custom(move |value: &String| {
+            if value.trim().is_empty() {
+                return Some(message.clone());
+            }
+
+            None
+        })
+    }
+
+    pub fn min_length(min: usize, message: impl Into<String>) -> Self {
+        let message = message.into();
+        Self::custom(move |value: &String| {
+            if value.chars().count() < min {
+                return Some(message.clone());
+            }
+
+            None
+        })
+    }
+
+    pub fn max_length(max: usize, message: impl Into<String>) -> Self {
+        let message = message.into();
+        Self::custom(move |value: &String| {
+            if value.chars().count() > max {
+                return Some(message.clone());
+            }
+
+            None
+        })
+    }
+
+    #[cfg(feature = "regex")]
+    pub fn regex(regex: regex::Regex, message: impl Into<String>) -> Self {
+        let message = message.into();
+        Self::custom(move |value: &String| {
+            if !regex.is_match(value) {
+                return Some(message.clone());
+            }
+
+            None
+        })
+    }
+}
+
+impl<T> FieldValidator<Option<T>> {
+    pub fn required_some(message: impl Into<String>) -> Self {
+        let message = message.into();
+        Self::custom(move |value: &Option<T>| {
+            if value.is_none() {
+                return Some(message.clone());
+            }
+
+            None
+        })
+    }
+}
+
+impl<T: PartialOrd + 'static> FieldValidator<T> {
+    pub fn range(min: T, max: T, message: impl Into<String>) -> Self {
+        let message = message.into();
+        Self::custom(move |value: &T| {
+            if *value < min || *value > max {
+                return Some(message.clone());
+            }
+
+            None
+        })
+    }
+}
+
+impl<T> Clone for FieldValidator<T> {
+    fn clone(&self) -> Self {
+        Self {
+            validate: self.validate.clone(),
+        }
+    }
+}
+
+pub struct FormField<T: Clone + Eq + Default> {
+    value: DialogValue<T>,
+    validators: Vec<FieldValidator<T>>,
+    touched: bool,
+}
+
+impl<T: Clone + Eq + Default> FormField<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value: DialogValue::new(value),
+            validators: Vec::new(),
+            touched: false,
+        }
+    }
+
+    pub fn with_validator(mut self, validator: FieldValidator<T>) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    pub fn init(&mut self, value: T) {
+        self.value.init(value);
+        self.touched = false;
+    }
+
+    pub fn set_value(&mut self, value: T) {
+        self.value.set_value(value);
+        self.touched = true;
+    }
+
+    pub fn get_init_value(&self) -> &T {
+        self.value.get_init_value()
+    }
+
+    pub fn get_value(&self) -> &T {
+        self.value.get_value()
+    }
+
+    pub fn get_value_mut(&mut self) -> &mut T {
+        self.touched = true;
+        self.value.get_value_mut()
+    }
+
+    pub fn is_value_updated(&self) -> bool {
+        self.value.is_value_updated()
+    }
+
+    pub fn get_dialog_value(&self) -> &DialogValue<T> {
+        &self.value
+    }
+
+    pub fn set_touched(&mut self) {
+        self.touched = true;
+    }
+
+    pub fn is_touched(&self) -> bool {
+        self.touched
+    }
+
+    pub fn get_error(&self) -> Option<String> {
+        self.validators
+            .iter()
+            .find_map(|validator| validator.validate(self.value.get_value()))
+    }
+
+    pub fn get_errors(&self) -> Vec<String> {
+        self.validators
+            .iter()
+            .filter_map(|validator| validator.validate(self.value.get_value()))
+            .collect()
+    }
+
+    pub fn get_visible_error(&self) -> Option<String> {
+        if !self.touched {
+            return None;
+        }
+
+        self.get_error()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.get_error().is_none()
+    }
+}
+
+impl FormField<String> {
+    pub fn as_str(&self) -> &str {
+        self.value.as_str()
+    }
+}
+
+impl<T: Clone + Eq + Default> Default for FormField<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+pub trait FormFieldState {
+    fn is_valid(&self) -> bool;
+    fn is_value_updated(&self) -> bool;
+    fn is_touched(&self) -> bool;
+    fn set_touched(&mut self);
+}
+
+impl<T: Clone + Eq + Default> FormFieldState for FormField<T> {
+    fn is_valid(&self) -> bool {
+        FormField::is_valid(self)
+    }
+
+    fn is_value_updated(&self) -> bool {
+        FormField::is_value_updated(self)
+    }
+
+    fn is_touched(&self) -> bool {
+        FormField::is_touched(self)
+    }
+
+    fn set_touched(&mut self) {
+        FormField::set_touched(self)
+    }
+}
+
+pub trait FormModel {
+    fn fields(&self) -> Vec<&dyn FormFieldState>;
+    fn fields_mut(&mut self) -> Vec<&mut dyn FormFieldState>;
+
+    fn is_valid(&self) -> bool {
+        self.fields().iter().all(|field| field.is_valid())
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.fields().iter().any(|field| field.is_value_updated())
+    }
+
+    fn touch_all(&mut self) {
+        for field in self.fields_mut() {
+            field.set_touched();
+        }
+    }
+}
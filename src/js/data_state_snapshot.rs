@@ -0,0 +1,69 @@
+use std::fmt::Debug;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::DataState;
+
+#[derive(Serialize)]
+struct DataStateSnapshotToSave<'s, T: Debug + Serialize, E: Debug + Serialize> {
+    schema_version: u32,
+    state: &'s DataState<T, E>,
+}
+
+#[derive(Deserialize)]
+struct DataStateSnapshotHeader {
+    schema_version: u32,
+}
+
+#[derive(Deserialize)]
+struct DataStateSnapshotToRestore<T: Debug, E: Debug> {
+    state: DataState<T, E>,
+}
+
+pub fn save_data_state_snapshot<T: Debug + Serialize, E: Debug + Serialize>(
+    key: &str,
+    schema_version: u32,
+    state: &DataState<T, E>,
+) -> bool {
+    if !state.has_value() {
+        return false;
+    }
+
+    let snapshot = DataStateSnapshotToSave {
+        schema_version,
+        state,
+    };
+
+    let Ok(json) = serde_json::to_string(&snapshot) else {
+        return false;
+    };
+
+    super::GlobalAppSettings::get_local_storage().try_set(key, json.as_str())
+}
+
+pub fn restore_data_state_snapshot<T, E>(key: &str, schema_version: u32) -> Option<DataState<T, E>>
+where
+    T: Debug + DeserializeOwned,
+    E: Debug + DeserializeOwned,
+{
+    let storage = super::GlobalAppSettings::get_local_storage();
+    let json = storage.get(key)?;
+
+    let header_matches = match serde_json::from_str::<DataStateSnapshotHeader>(json.as_str()) {
+        Ok(header) => header.schema_version == schema_version,
+        Err(_) => false,
+    };
+
+    if !header_matches {
+        storage.delete(key);
+        return None;
+    }
+
+    match serde_json::from_str::<DataStateSnapshotToRestore<T, E>>(json.as_str()) {
+        Ok(snapshot) if snapshot.state.has_value() => Some(snapshot.state),
+        _ => {
+            storage.delete(key);
+            None
+        }
+    }
+}
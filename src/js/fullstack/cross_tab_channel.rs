@@ -0,0 +1,164 @@
+use std::marker::PhantomData;
+
+use js_sys::wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+thread_local! {
+    // BroadcastChannel also delivers to other channel objects of the same tab,
+    // so every message carries the sender tab and is dropped by that tab
+    static TAB_ID: String = generate_id();
+}
+
+// `crypto.randomUUID` is missing on insecure origins and in the browsers that
+// need the storage fallback, so ids are built from the clock and Math.random
+fn generate_id() -> String {
+    let random = (js_sys::Math::random() * u32::MAX as f64) as u32;
+    format!("{:x}-{:08x}", js_sys::Date::now() as u64, random)
+}
+
+#[derive(Serialize)]
+struct CrossTabMessageToSend<'s, T: Serialize> {
+    id: String,
+    tab_id: &'s str,
+    message: &'s T,
+}
+
+#[derive(Deserialize)]
+struct CrossTabMessage<T> {
+    tab_id: String,
+    message: T,
+}
+
+fn parse_message<T: DeserializeOwned>(json: &str, tab_id: &str) -> Option<T> {
+    let message = serde_json::from_str::<CrossTabMessage<T>>(json).ok()?;
+
+    if message.tab_id == tab_id {
+        return None;
+    }
+
+    Some(message.message)
+}
+
+pub struct CrossTabChannel<T: Serialize + DeserializeOwned + 'static> {
+    tab_id: String,
+    storage_key: String,
+    broadcast_channel: Option<web_sys::BroadcastChannel>,
+    on_broadcast_message: Option<Closure<dyn FnMut(web_sys::MessageEvent)>>,
+    on_storage_event: Option<Closure<dyn FnMut(web_sys::StorageEvent)>>,
+    message_type: PhantomData<T>,
+}
+
+impl<T: Serialize + DeserializeOwned + 'static> CrossTabChannel<T> {
+    pub fn new(name: &str) -> Self {
+        Self {
+            tab_id: TAB_ID.with(|tab_id| tab_id.clone()),
+            storage_key: format!("cross-tab-channel:{}", name),
+            broadcast_channel: web_sys::BroadcastChannel::new(name).ok(),
+            on_broadcast_message: None,
+            on_storage_event: None,
+            message_type: PhantomData,
+        }
+    }
+
+    pub fn post(&self, message: &T) {
+        let to_send = CrossTabMessageToSend {
+            id: generate_id(),
+            tab_id: self.tab_id.as_str(),
+            message,
+        };
+
+        let Ok(json) = serde_json::to_string(&to_send) else {
+            return;
+        };
+
+        if let Some(broadcast_channel) = &self.broadcast_channel {
+            let _ = broadcast_channel.post_message(&JsValue::from_str(json.as_str()));
+            return;
+        }
+
+        let Some(storage) = super::GlobalAppSettings::try_get_local_storage() else {
+            return;
+        };
+
+        if storage.try_set(self.storage_key.as_str(), json.as_str()) {
+            storage.delete(self.storage_key.as_str());
+        }
+    }
+
+    pub fn on_message(&mut self, mut callback: impl FnMut(T) + 'static) {
+        self.remove_listeners();
+
+        let tab_id = self.tab_id.clone();
+
+        if let Some(broadcast_channel) = &self.broadcast_channel {
+            let on_message = Closure::<dyn FnMut(web_sys::MessageEvent)>::new(
+                move |event: web_sys::MessageEvent| {
+                    let Some(json) = event.data().as_string() else {
+                        return;
+                    };
+
+                    if let Some(message) = parse_message(json.as_str(), tab_id.as_str()) {
+                        callback(message);
+                    }
+                },
+            );
+
+            broadcast_channel.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+            self.on_broadcast_message = Some(on_message);
+            return;
+        }
+
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+
+        let storage_key = self.storage_key.clone();
+
+        let on_storage = Closure::<dyn FnMut(web_sys::StorageEvent)>::new(
+            move |event: web_sys::StorageEvent| {
+                if event.key().as_deref() != Some(storage_key.as_str()) {
+                    return;
+                }
+
+                let Some(json) = event.new_value() else {
+                    return;
+                };
+
+                if let Some(message) = parse_message(json.as_str(), tab_id.as_str()) {
+                    callback(message);
+                }
+            },
+        );
+
+        let _ =
+            window.add_event_listener_with_callback("storage", on_storage.as_ref().unchecked_ref());
+        self.on_storage_event = Some(on_storage);
+    }
+
+    fn remove_listeners(&mut self) {
+        if let Some(broadcast_channel) = &self.broadcast_channel {
+            broadcast_channel.set_onmessage(None);
+        }
+
+        self.on_broadcast_message = None;
+
+        if let Some(on_storage) = self.on_storage_event.take() {
+            if let Some(window) = web_sys::window() {
+                let _ = window.remove_event_listener_with_callback(
+                    "storage",
+                    on_storage.as_ref().unchecked_ref(),
+                );
+            }
+        }
+    }
+}
+
+impl<T: Serialize + DeserializeOwned + 'static> Drop for CrossTabChannel<T> {
+    fn drop(&mut self) {
+        self.remove_listeners();
+
+        if let Some(broadcast_channel) = &self.broadcast_channel {
+            broadcast_channel.close();
+        }
+    }
+}
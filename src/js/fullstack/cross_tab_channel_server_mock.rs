@@ -0,0 +1,19 @@
+use std::marker::PhantomData;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+pub struct CrossTabChannel<T: Serialize + DeserializeOwned + 'static> {
+    message_type: PhantomData<T>,
+}
+
+impl<T: Serialize + DeserializeOwned + 'static> CrossTabChannel<T> {
+    pub fn new(_name: &str) -> Self {
+        Self {
+            message_type: PhantomData,
+        }
+    }
+
+    pub fn post(&self, _message: &T) {}
+
+    pub fn on_message(&mut self, _callback: impl FnMut(T) + 'static) {}
+}
@@ -8,6 +8,16 @@ mod set_focus_server_mock;
 #[cfg(feature = "server")]
 pub use set_focus_server_mock::*;
 
+#[cfg(all(feature = "serde", not(feature = "server")))]
+mod cross_tab_channel;
+#[cfg(all(feature = "serde", not(feature = "server")))]
+pub use cross_tab_channel::*;
+
+#[cfg(all(feature = "serde", feature = "server"))]
+mod cross_tab_channel_server_mock;
+#[cfg(all(feature = "serde", feature = "server"))]
+pub use cross_tab_channel_server_mock::*;
+
 mod web_local_storage;
 pub use web_local_storage::*;
 
@@ -65,4 +75,15 @@ impl GlobalAppSettings {
         #[cfg(feature = "server")]
         return super::WebLocalStorage::new();
     }
+
+    pub fn try_get_local_storage() -> Option<super::WebLocalStorage> {
+        #[cfg(not(feature = "server"))]
+        return web_sys::window()?
+            .local_storage()
+            .ok()?
+            .map(|storage| storage.into());
+
+        #[cfg(feature = "server")]
+        return None;
+    }
 }
@@ -21,6 +21,14 @@ impl WebLocalStorage {
         self.storage.set(_key, _value).unwrap();
     }
 
+    pub fn try_set(&self, _key: &str, _value: &str) -> bool {
+        #[cfg(not(feature = "server"))]
+        return self.storage.set(_key, _value).is_ok();
+
+        #[cfg(feature = "server")]
+        return false;
+    }
+
     pub fn delete(&self, _key: &str) {
         #[cfg(not(feature = "server"))]
         self.storage.delete(_key).unwrap();
@@ -6,3 +6,7 @@ pub use fullstack::*;
 mod web;
 #[cfg(feature = "web")]
 pub use web::*;
+#[cfg(all(feature = "serde", any(feature = "fullstack", feature = "web")))]
+mod data_state_snapshot;
+#[cfg(all(feature = "serde", any(feature = "fullstack", feature = "web")))]
+pub use data_state_snapshot::*;
@@ -99,16 +99,32 @@ impl FlUrl {
         &self,
         body: impl Into<super::HttpRequestBody>,
     ) -> reqwest::Result<FlUrlResponse> {
-        let body = body.into();
-        let content_type = body.get_content_type();
+        let body: super::HttpRequestBody = body.into();
+        self.send_with_body(reqwest::Method::POST, body.into())
+            .await
+    }
+
+    pub async fn patch(&self, body: impl Into<super::FlUrlBody>) -> reqwest::Result<FlUrlResponse> {
+        self.send_with_body(reqwest::Method::PATCH, body.into())
+            .await
+    }
+
+    async fn send_with_body(
+        &self,
+        method: reqwest::Method,
+        body: super::FlUrlBody,
+    ) -> reqwest::Result<FlUrlResponse> {
+        let content_type = body
+            .get_content_type()
+            .map(|content_type| content_type.to_string());
         let as_vec = body.into_vec();
 
         let client = reqwest::Client::new();
 
         let path_and_query = self.get_path_and_query();
-        crate::console_log(format!("[POST] {}", path_and_query.as_str()));
+        crate::console_log(format!("[{}] {}", method, path_and_query.as_str()));
 
-        let mut req_builder = client.post(path_and_query.as_str());
+        let mut req_builder = client.request(method, path_and_query.as_str());
 
         if let Some(content_type) = content_type {
             req_builder = req_builder.header("Content-Type", content_type.as_str());
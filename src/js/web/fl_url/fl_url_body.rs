@@ -0,0 +1,48 @@
+use super::HttpRequestBody;
+
+pub struct FlUrlBody {
+    content_type: Option<String>,
+    data: Vec<u8>,
+}
+
+impl FlUrlBody {
+    pub fn new(content_type: impl Into<String>, data: Vec<u8>) -> Self {
+        Self {
+            content_type: Some(content_type.into()),
+            data,
+        }
+    }
+
+    pub fn as_merge_patch(value: &serde_json::Value) -> Self {
+        Self::new(
+            "application/merge-patch+json",
+            value.to_string().into_bytes(),
+        )
+    }
+
+    pub fn as_json_patch(value: &serde_json::Value) -> Self {
+        Self::new(
+            "application/json-patch+json",
+            value.to_string().into_bytes(),
+        )
+    }
+
+    pub fn get_content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    pub fn into_vec(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+impl From<HttpRequestBody> for FlUrlBody {
+    fn from(body: HttpRequestBody) -> Self {
+        Self {
+            content_type: body
+                .get_content_type()
+                .map(|content_type| content_type.as_str().to_string()),
+            data: body.into_vec(),
+        }
+    }
+}
@@ -1,5 +1,7 @@
 mod fl_url;
 pub use fl_url::*;
+mod fl_url_body;
+pub use fl_url_body::*;
 mod json_body_builder;
 pub use json_body_builder::*;
 pub use url_utils::body::*;
@@ -7,6 +7,10 @@ pub use reload_page::*;
 mod web_local_storage;
 pub use web_local_storage::*;
 pub mod fl_url;
+#[cfg(feature = "serde")]
+mod cross_tab_channel;
+#[cfg(feature = "serde")]
+pub use cross_tab_channel::*;
 
 pub struct GlobalAppSettings {
     href: String,
@@ -52,4 +56,9 @@ impl GlobalAppSettings {
             .unwrap()
             .into();
     }
+
+    pub fn try_get_local_storage() -> Option<super::WebLocalStorage> {
+        let storage = web_sys::window()?.local_storage().ok()??;
+        Some(storage.into())
+    }
 }
@@ -12,6 +12,10 @@ impl WebLocalStorage {
         self.storage.set(_key, _value).unwrap();
     }
 
+    pub fn try_set(&self, key: &str, value: &str) -> bool {
+        self.storage.set(key, value).is_ok()
+    }
+
     pub fn delete(&self, _key: &str) {
         #[cfg(not(feature = "server"))]
         self.storage.delete(_key).unwrap();
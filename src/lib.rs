@@ -17,4 +17,44 @@ pub use generate_uuid::*;
 mod now_date_time;
 pub use now_date_time::*;
 mod notify_child_components;
-pub use notify_child_components::*;
\ No newline at end of file
+pub use notify_child_components::*;
+mod use_data_state;
+pub use use_data_state::*;
+mod paged_data_state;
+pub use paged_data_state::*;
+mod retry_policy;
+pub use retry_policy::*;
+mod query_cache;
+pub use query_cache::*;
+mod loading_progress;
+pub use loading_progress::*;
+mod render_resource;
+pub use render_resource::*;
+mod render_data_state;
+pub use render_data_state::*;
+mod aggregate_state;
+pub use aggregate_state::*;
+mod form_field;
+pub use form_field::*;
+#[cfg(feature = "macros")]
+pub use dioxus_utils_macros::DialogForm;
+mod dialog_value_with_history;
+pub use dialog_value_with_history::*;
+mod async_validator;
+pub use async_validator::*;
+#[cfg(feature = "serde")]
+mod dialog_value_diff;
+#[cfg(feature = "serde")]
+pub use dialog_value_diff::*;
+mod unsaved_changes_guard;
+pub use unsaved_changes_guard::*;
+mod dialog_value_list;
+pub use dialog_value_list::*;
+mod event_bus;
+pub use event_bus::*;
+mod child_request_channel;
+pub use child_request_channel::*;
+#[cfg(all(feature = "serde", any(feature = "fullstack", feature = "web")))]
+mod use_cross_tab_channel;
+#[cfg(all(feature = "serde", any(feature = "fullstack", feature = "web")))]
+pub use use_cross_tab_channel::*;
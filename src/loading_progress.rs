@@ -0,0 +1,27 @@
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LoadingProgress {
+    pub fraction: Option<f64>,
+    pub bytes: Option<(u64, u64)>,
+    pub step: Option<String>,
+}
+
+impl LoadingProgress {
+    pub fn get_fraction(&self) -> Option<f64> {
+        if let Some(fraction) = self.fraction {
+            return Some(fraction.clamp(0.0, 1.0));
+        }
+
+        let (done, total) = self.bytes?;
+
+        if total == 0 {
+            return None;
+        }
+
+        Some((done as f64 / total as f64).clamp(0.0, 1.0))
+    }
+
+    pub fn get_percent(&self) -> Option<u8> {
+        let fraction = self.get_fraction()?;
+        Some((fraction * 100.0).round() as u8)
+    }
+}
@@ -56,3 +56,22 @@ pub fn now_local_date_time() -> DateTimeAsMicroseconds {
 
     result.unwrap()
 }
+
+#[cfg(feature = "server")]
+pub(crate) fn now_timestamp() -> DateTimeAsMicroseconds {
+    DateTimeAsMicroseconds::now()
+}
+
+#[cfg(all(not(feature = "server"), target_arch = "wasm32"))]
+pub(crate) fn now_timestamp() -> DateTimeAsMicroseconds {
+    let unix_milliseconds = js_sys::Date::now();
+    DateTimeAsMicroseconds::new((unix_milliseconds * 1000.0) as i64)
+}
+
+#[cfg(all(not(feature = "server"), not(target_arch = "wasm32")))]
+pub(crate) fn now_timestamp() -> DateTimeAsMicroseconds {
+    let since_epoch = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+    DateTimeAsMicroseconds::new(since_epoch.as_micros() as i64)
+}
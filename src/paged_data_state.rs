@@ -0,0 +1,210 @@
+use std::fmt::Debug;
+
+use crate::RenderState;
+
+#[derive(Debug)]
+pub struct PagedDataState<T: Debug, TCursor: Debug = usize, E: Debug = String> {
+    inner: RenderState<Vec<T>, E>,
+    pages_loaded: usize,
+    next_cursor: Option<TCursor>,
+    loading_next_page: bool,
+    next_page_error: Option<E>,
+    generation: u64,
+}
+
+impl<T: Debug, TCursor: Debug, E: Debug> PagedDataState<T, TCursor, E> {
+    pub fn new_typed() -> Self {
+        Self {
+            inner: RenderState::None,
+            pages_loaded: 0,
+            next_cursor: None,
+            loading_next_page: false,
+            next_page_error: None,
+            generation: 0,
+        }
+    }
+
+    pub fn is_none(&self) -> bool {
+        self.inner.is_none()
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.inner.is_loading()
+    }
+
+    pub fn has_value(&self) -> bool {
+        self.inner.has_value()
+    }
+
+    pub fn set_loading(&mut self) {
+        self.reset();
+        self.inner.set_loading();
+    }
+
+    pub fn set_first_page(&mut self, items: Vec<T>, next_cursor: Option<TCursor>) {
+        self.inner.set_loaded(items);
+        self.pages_loaded = 1;
+        self.next_cursor = next_cursor;
+        self.loading_next_page = false;
+        self.next_page_error = None;
+    }
+
+    pub fn set_loading_next_page(&mut self) -> Option<&TCursor> {
+        if !self.can_load_next_page() {
+            return None;
+        }
+
+        self.loading_next_page = true;
+        self.next_page_error = None;
+        self.next_cursor.as_ref()
+    }
+
+    pub fn append_page(&mut self, items: Vec<T>, next_cursor: Option<TCursor>) {
+        if !self.loading_next_page {
+            return;
+        }
+
+        let Some(loaded) = self.inner.try_unwrap_as_loaded_mut() else {
+            return;
+        };
+
+        loaded.extend(items);
+        self.pages_loaded += 1;
+        self.next_cursor = next_cursor;
+        self.loading_next_page = false;
+        self.next_page_error = None;
+    }
+
+    pub fn set_typed_error(&mut self, err: E) {
+        self.loading_next_page = false;
+
+        if self.pages_loaded == 0 {
+            self.inner.set_typed_error(err);
+            return;
+        }
+
+        self.next_page_error = Some(err);
+    }
+
+    pub fn reset(&mut self) {
+        self.next_generation();
+        self.inner = RenderState::None;
+        self.pages_loaded = 0;
+        self.next_cursor = None;
+        self.loading_next_page = false;
+        self.next_page_error = None;
+    }
+
+    pub fn start_loading(&mut self) -> u64 {
+        self.set_loading();
+        self.generation
+    }
+
+    pub fn get_generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn is_current_generation(&self, generation: u64) -> bool {
+        self.generation == generation
+    }
+
+    pub fn set_first_page_if_current(
+        &mut self,
+        generation: u64,
+        items: Vec<T>,
+        next_cursor: Option<TCursor>,
+    ) -> bool {
+        if !self.is_current_generation(generation) {
+            return false;
+        }
+
+        self.set_first_page(items, next_cursor);
+        true
+    }
+
+    pub fn append_page_if_current(
+        &mut self,
+        generation: u64,
+        items: Vec<T>,
+        next_cursor: Option<TCursor>,
+    ) -> bool {
+        if !self.is_current_generation(generation) || !self.loading_next_page {
+            return false;
+        }
+
+        self.append_page(items, next_cursor);
+        true
+    }
+
+    pub fn set_typed_error_if_current(&mut self, generation: u64, err: E) -> bool {
+        if !self.is_current_generation(generation) {
+            return false;
+        }
+
+        self.set_typed_error(err);
+        true
+    }
+
+    fn next_generation(&mut self) {
+        self.generation += 1;
+    }
+
+    pub fn get_items(&self) -> &[T] {
+        match &self.inner {
+            RenderState::Loaded(items) => items.as_slice(),
+            _ => &[],
+        }
+    }
+
+    pub fn get_pages_loaded(&self) -> usize {
+        self.pages_loaded
+    }
+
+    pub fn get_next_cursor(&self) -> Option<&TCursor> {
+        self.next_cursor.as_ref()
+    }
+
+    pub fn has_more(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+
+    pub fn is_loading_next_page(&self) -> bool {
+        self.loading_next_page
+    }
+
+    pub fn can_load_next_page(&self) -> bool {
+        self.has_value() && self.has_more() && !self.loading_next_page
+    }
+
+    pub fn get_next_page_error(&self) -> Option<&E> {
+        self.next_page_error.as_ref()
+    }
+
+    pub fn try_unwrap_as_error(&self) -> Option<&E> {
+        self.inner.try_unwrap_as_error()
+    }
+
+    pub fn as_ref(&self) -> &RenderState<Vec<T>, E> {
+        &self.inner
+    }
+}
+
+impl<T: Debug, TCursor: Debug> PagedDataState<T, TCursor, String> {
+    pub fn new() -> Self {
+        Self::new_typed()
+    }
+
+    pub fn set_error(&mut self, err: impl Debug) {
+        self.set_typed_error(format!("{:?}", err));
+    }
+
+    pub fn set_error_if_current(&mut self, generation: u64, err: impl Debug) -> bool {
+        self.set_typed_error_if_current(generation, format!("{:?}", err))
+    }
+}
+
+impl<T: Debug, TCursor: Debug, E: Debug> Default for PagedDataState<T, TCursor, E> {
+    fn default() -> Self {
+        Self::new_typed()
+    }
+}
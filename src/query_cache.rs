@@ -0,0 +1,226 @@
+use std::{
+    any::Any, borrow::Borrow, collections::HashMap, fmt::Debug, future::Future, hash::Hash, rc::Rc,
+};
+
+use dioxus::{
+    core::{current_scope_id, Runtime, ScopeId},
+    prelude::*,
+};
+
+use crate::RenderState;
+
+struct QueryCacheEntry {
+    generation: u64,
+    state: Rc<dyn Any>,
+}
+
+pub struct QueryCache<K: Hash + Eq + Clone + Debug + 'static = String> {
+    entries: CopyValue<HashMap<K, QueryCacheEntry>>,
+    next_generation: CopyValue<u64>,
+    version: Signal<u64>,
+    scope_id: ScopeId,
+}
+
+impl<K: Hash + Eq + Clone + Debug + 'static> Clone for QueryCache<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: Hash + Eq + Clone + Debug + 'static> Copy for QueryCache<K> {}
+
+impl<K: Hash + Eq + Clone + Debug + 'static> PartialEq for QueryCache<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.version == other.version
+    }
+}
+
+impl<K: Hash + Eq + Clone + Debug + 'static> QueryCache<K> {
+    fn new() -> Self {
+        Self {
+            entries: CopyValue::new(HashMap::new()),
+            next_generation: CopyValue::new(0),
+            version: Signal::new(0),
+            scope_id: current_scope_id(),
+        }
+    }
+
+    pub fn get<T, E, Q>(&self, key: &Q) -> Option<Rc<RenderState<T, E>>>
+    where
+        T: Debug + 'static,
+        E: Debug + 'static,
+        K: Borrow<Q>,
+        Q: Hash + Eq + Debug + ?Sized,
+    {
+        let _ = self.version.read();
+
+        let entries = self.entries.read();
+        let state = entries.get(key)?.state.clone();
+
+        match state.downcast::<RenderState<T, E>>() {
+            Ok(state) => Some(state),
+            Err(_) => panic!("Query cache entry {:?} holds a value of another type", key),
+        }
+    }
+
+    pub fn fetch<T, E, TFuture>(&self, key: impl Into<K>, future: TFuture)
+    where
+        T: Debug + 'static,
+        E: Debug + 'static,
+        TFuture: Future<Output = Result<T, E>> + 'static,
+    {
+        let key = key.into();
+
+        let mut next_generation = self.next_generation;
+        let generation = *next_generation.read() + 1;
+        next_generation.set(generation);
+
+        let mut entries = self.entries;
+        entries.write().insert(
+            key.clone(),
+            QueryCacheEntry {
+                generation,
+                state: Rc::new(RenderState::<T, E>::Loading),
+            },
+        );
+
+        let cache = *self;
+
+        Runtime::current().in_scope(self.scope_id, || {
+            spawn(async move {
+                let state = match future.await {
+                    Ok(value) => RenderState::<T, E>::Loaded(value),
+                    Err(err) => RenderState::<T, E>::Error(err),
+                };
+
+                cache.complete(&key, generation, state);
+            })
+        });
+    }
+
+    pub fn invalidate<Q>(&self, key: &Q)
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let mut entries = self.entries;
+        let removed = entries.write().remove(key).is_some();
+
+        if removed {
+            self.notify();
+        }
+    }
+
+    pub fn invalidate_where(&self, predicate: impl Fn(&K) -> bool) {
+        let mut entries = self.entries;
+        let removed = {
+            let mut entries = entries.write();
+            let count_before = entries.len();
+            entries.retain(|key, _| !predicate(key));
+            entries.len() != count_before
+        };
+
+        if removed {
+            self.notify();
+        }
+    }
+
+    pub fn invalidate_all(&self) {
+        let mut entries = self.entries;
+        entries.write().clear();
+        self.notify();
+    }
+
+    fn complete<T: Debug + 'static, E: Debug + 'static>(
+        &self,
+        key: &K,
+        generation: u64,
+        state: RenderState<T, E>,
+    ) {
+        let mut entries = self.entries;
+
+        {
+            let mut entries = entries.write();
+
+            let Some(entry) = entries.get_mut(key) else {
+                return;
+            };
+
+            if entry.generation != generation {
+                return;
+            }
+
+            entry.state = Rc::new(state);
+        }
+
+        self.notify();
+    }
+
+    fn notify(&self) {
+        let mut version = self.version;
+        *version.write() += 1;
+    }
+}
+
+impl QueryCache<String> {
+    pub fn invalidate_prefix(&self, prefix: &str) {
+        self.invalidate_where(|key| key.starts_with(prefix));
+    }
+}
+
+pub fn use_query_cache_provider() -> QueryCache {
+    use_query_cache_provider_typed()
+}
+
+pub fn use_query_cache_provider_typed<K: Hash + Eq + Clone + Debug + 'static>() -> QueryCache<K> {
+    use_context_provider(QueryCache::new)
+}
+
+pub fn use_query_cache() -> QueryCache {
+    use_query_cache_typed()
+}
+
+pub fn use_query_cache_typed<K: Hash + Eq + Clone + Debug + 'static>() -> QueryCache<K> {
+    use_context()
+}
+
+pub fn use_query<T, E, TFuture>(
+    key: &str,
+    loader: impl FnOnce() -> TFuture,
+) -> Rc<RenderState<T, E>>
+where
+    T: Debug + 'static,
+    E: Debug + 'static,
+    TFuture: Future<Output = Result<T, E>> + 'static,
+{
+    let cache = use_query_cache();
+
+    if let Some(state) = cache.get::<T, E, _>(key) {
+        return state;
+    }
+
+    cache.fetch::<T, E, _>(key, loader());
+
+    Rc::new(RenderState::Loading)
+}
+
+pub fn use_query_typed<K, T, E, TFuture>(
+    key: K,
+    loader: impl FnOnce() -> TFuture,
+) -> Rc<RenderState<T, E>>
+where
+    K: Hash + Eq + Clone + Debug + 'static,
+    T: Debug + 'static,
+    E: Debug + 'static,
+    TFuture: Future<Output = Result<T, E>> + 'static,
+{
+    let cache = use_query_cache_typed::<K>();
+
+    if let Some(state) = cache.get::<T, E, _>(&key) {
+        return state;
+    }
+
+    cache.fetch::<T, E, _>(key, loader());
+
+    Rc::new(RenderState::Loading)
+}
@@ -0,0 +1,162 @@
+use std::{
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+use dioxus::prelude::*;
+
+use crate::{DataState, RenderState};
+
+#[derive(Clone)]
+pub struct RetryCallback {
+    callback: Rc<dyn Fn()>,
+}
+
+impl RetryCallback {
+    pub fn new(callback: impl Fn() + 'static) -> Self {
+        Self {
+            callback: Rc::new(callback),
+        }
+    }
+
+    pub fn retry(&self) {
+        (self.callback)();
+    }
+}
+
+#[derive(Clone)]
+pub struct RenderStateDefaults {
+    none: Rc<dyn Fn() -> Element>,
+    loading: Rc<dyn Fn() -> Element>,
+    error: Rc<dyn Fn(String, RetryCallback) -> Element>,
+}
+
+impl RenderStateDefaults {
+    pub fn new() -> Self {
+        Self {
+            none: Rc::new(|| rsx! {}),
+            loading: Rc::new(|| rsx! { "Loading..." }),
+            error: Rc::new(|message, retry| {
+                rsx! {
+                    div { "{message}" }
+                    button { onclick: move |_| retry.retry(), "Retry" }
+                }
+            }),
+        }
+    }
+
+    pub fn with_none(mut self, none: impl Fn() -> Element + 'static) -> Self {
+        self.none = Rc::new(none);
+        self
+    }
+
+    pub fn with_loading(mut self, loading: impl Fn() -> Element + 'static) -> Self {
+        self.loading = Rc::new(loading);
+        self
+    }
+
+    pub fn with_error(
+        mut self,
+        error: impl Fn(String, RetryCallback) -> Element + 'static,
+    ) -> Self {
+        self.error = Rc::new(error);
+        self
+    }
+}
+
+impl Default for RenderStateDefaults {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn use_render_state_defaults_provider(
+    init: impl FnOnce() -> RenderStateDefaults,
+) -> RenderStateDefaults {
+    use_context_provider(init)
+}
+
+type ErrorRenderer<E> = dyn Fn(&E, RetryCallback) -> Element;
+
+pub struct RenderDataState<T: Debug + 'static, E: Debug + 'static = String> {
+    state: Signal<DataState<T, E>>,
+    none: Option<Rc<dyn Fn() -> Element>>,
+    loading: Option<Rc<dyn Fn() -> Element>>,
+    error: Option<Rc<ErrorRenderer<E>>>,
+    error_message: Rc<dyn Fn(&E) -> String>,
+    on_retry: Option<RetryCallback>,
+}
+
+impl<T: Debug + 'static, E: Debug + Display + 'static> RenderDataState<T, E> {
+    pub fn new(state: Signal<DataState<T, E>>) -> Self {
+        Self::new_with_error_message(state, |err| err.to_string())
+    }
+}
+
+impl<T: Debug + 'static, E: Debug + 'static> RenderDataState<T, E> {
+    pub fn new_with_error_message(
+        state: Signal<DataState<T, E>>,
+        error_message: impl Fn(&E) -> String + 'static,
+    ) -> Self {
+        Self {
+            state,
+            none: None,
+            loading: None,
+            error: None,
+            error_message: Rc::new(error_message),
+            on_retry: None,
+        }
+    }
+
+    pub fn with_none(mut self, none: impl Fn() -> Element + 'static) -> Self {
+        self.none = Some(Rc::new(none));
+        self
+    }
+
+    pub fn with_loading(mut self, loading: impl Fn() -> Element + 'static) -> Self {
+        self.loading = Some(Rc::new(loading));
+        self
+    }
+
+    pub fn with_error(mut self, error: impl Fn(&E, RetryCallback) -> Element + 'static) -> Self {
+        self.error = Some(Rc::new(error));
+        self
+    }
+
+    pub fn with_on_retry(mut self, on_retry: impl Fn() + 'static) -> Self {
+        self.on_retry = Some(RetryCallback::new(on_retry));
+        self
+    }
+
+    pub fn render(self, loaded: impl FnOnce(&T) -> Element) -> Element {
+        let defaults = try_consume_context::<RenderStateDefaults>().unwrap_or_default();
+
+        let state = self.state.read();
+
+        match state.as_ref() {
+            RenderState::None => {
+                let none = self.none.unwrap_or(defaults.none);
+                none()
+            }
+            RenderState::Loading => {
+                let loading = self.loading.unwrap_or(defaults.loading);
+                loading()
+            }
+            RenderState::Loaded(value) => loaded(value),
+            RenderState::Error(err) => {
+                let data_state = self.state;
+                let retry = self.on_retry.unwrap_or_else(|| {
+                    RetryCallback::new(move || {
+                        let mut data_state = data_state;
+                        data_state.write().reset();
+                    })
+                });
+
+                match self.error {
+                    Some(error) => error(err, retry),
+                    None => (defaults.error)((self.error_message)(err), retry),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt::Debug;
+
+use dioxus::{
+    core::{CapturedError, RenderError},
+    prelude::*,
+};
+
+use crate::RenderState;
+
+impl<T: Debug + Clone, E: Debug + Clone> From<Resource<Result<T, E>>> for RenderState<T, E> {
+    fn from(resource: Resource<Result<T, E>>) -> Self {
+        if let Some(result) = &*resource.read() {
+            return match result {
+                Ok(value) => RenderState::Loaded(value.clone()),
+                Err(err) => RenderState::Error(err.clone()),
+            };
+        }
+
+        match resource.state().cloned() {
+            UseResourceState::Pending => RenderState::Loading,
+            _ => RenderState::None,
+        }
+    }
+}
+
+impl<T: Debug + Clone, E: Debug + Clone> From<&Resource<Result<T, E>>> for RenderState<T, E> {
+    fn from(resource: &Resource<Result<T, E>>) -> Self {
+        (*resource).into()
+    }
+}
+
+#[component]
+pub fn RenderResource<
+    T: Debug + Clone + PartialEq + 'static,
+    E: Debug + Clone + PartialEq + 'static,
+>(
+    resource: Resource<Result<T, E>>,
+    loaded: Callback<T, Element>,
+    none: Option<Callback<(), Element>>,
+    loading: Option<Callback<(), Element>>,
+    error: Option<Callback<E, Element>>,
+) -> Element {
+    if loading.is_none() {
+        resource.suspend()?;
+    }
+
+    match RenderState::from(resource) {
+        RenderState::None => match none {
+            Some(none) => none.call(()),
+            None => rsx! {},
+        },
+        RenderState::Loading => match loading {
+            Some(loading) => loading.call(()),
+            None => rsx! {},
+        },
+        RenderState::Loaded(value) => loaded.call(value),
+        RenderState::Error(err) => match error {
+            Some(error) => error.call(err),
+            None => Err(RenderError::Error(CapturedError::from_display(format!(
+                "{:?}",
+                err
+            )))),
+        },
+    }
+}
@@ -0,0 +1,131 @@
+use std::{rc::Rc, time::Duration};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryAttempt {
+    pub attempt: usize,
+    pub max_attempts: usize,
+}
+
+pub struct RetryPolicy<E> {
+    max_attempts: usize,
+    initial_delay: Duration,
+    max_delay: Duration,
+    multiplier: u32,
+    retry_if: Rc<dyn Fn(&E) -> bool>,
+}
+
+impl<E> RetryPolicy<E> {
+    pub fn new(max_attempts: usize) -> Self {
+        Self {
+            max_attempts,
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            multiplier: 2,
+            retry_if: Rc::new(|_| true),
+        }
+    }
+
+    pub fn with_initial_delay(mut self, initial_delay: Duration) -> Self {
+        self.initial_delay = initial_delay;
+        self
+    }
+
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn with_multiplier(mut self, multiplier: u32) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    pub fn with_retry_if(mut self, retry_if: impl Fn(&E) -> bool + 'static) -> Self {
+        self.retry_if = Rc::new(retry_if);
+        self
+    }
+
+    pub fn get_max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    pub fn should_retry(&self, attempt: usize, err: &E) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+
+        (self.retry_if)(err)
+    }
+
+    pub fn get_delay(&self, attempt: usize) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(u32::MAX as usize) as u32;
+        let factor = self.multiplier.saturating_pow(exponent);
+        self.initial_delay
+            .saturating_mul(factor)
+            .min(self.max_delay)
+    }
+}
+
+impl<E> Clone for RetryPolicy<E> {
+    fn clone(&self) -> Self {
+        Self {
+            max_attempts: self.max_attempts,
+            initial_delay: self.initial_delay,
+            max_delay: self.max_delay,
+            multiplier: self.multiplier,
+            retry_if: self.retry_if.clone(),
+        }
+    }
+}
+
+#[cfg(any(feature = "fullstack", feature = "web"))]
+pub async fn load_with_retry<T, E, TFuture>(
+    mut state: dioxus::prelude::Signal<crate::DataState<T, E>>,
+    policy: &RetryPolicy<E>,
+    mut loader: impl FnMut() -> TFuture,
+) where
+    T: std::fmt::Debug + 'static,
+    E: std::fmt::Debug + 'static,
+    TFuture: std::future::Future<Output = Result<T, E>>,
+{
+    use dioxus::prelude::*;
+
+    let generation = state.write().start_loading();
+
+    let mut attempt = 1;
+
+    loop {
+        let result = loader().await;
+
+        if !state.peek().is_current_generation(generation) {
+            return;
+        }
+
+        let err = match result {
+            Ok(value) => {
+                state.write().set_loaded_if_current(generation, value);
+                return;
+            }
+            Err(err) => err,
+        };
+
+        if !policy.should_retry(attempt, &err) {
+            state.write().set_typed_error_if_current(generation, err);
+            return;
+        }
+
+        let delay = policy.get_delay(attempt);
+        attempt += 1;
+
+        state.write().set_retry_attempt(RetryAttempt {
+            attempt,
+            max_attempts: policy.get_max_attempts(),
+        });
+
+        crate::js::sleep(delay).await;
+
+        if !state.peek().is_current_generation(generation) {
+            return;
+        }
+    }
+}
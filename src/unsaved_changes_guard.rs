@@ -0,0 +1,469 @@
+use std::{cell::RefCell, collections::HashMap, future::Future, pin::Pin, rc::Rc, sync::Arc};
+
+use dioxus::{
+    core::{current_scope_id, Runtime, ScopeId},
+    history::{provide_history_context, History},
+    prelude::*,
+};
+
+type ConfirmCallback = dyn Fn() -> Pin<Box<dyn Future<Output = bool>>>;
+
+type RouterUpdate = Arc<dyn Fn() + Send + Sync>;
+
+#[derive(Clone, Copy)]
+pub struct UnsavedChangesGuard {
+    forms: CopyValue<HashMap<u64, bool>>,
+    next_id: CopyValue<u64>,
+    confirm: CopyValue<Rc<ConfirmCallback>>,
+    confirming: CopyValue<bool>,
+    scope_id: ScopeId,
+}
+
+impl UnsavedChangesGuard {
+    fn new(confirm: Rc<ConfirmCallback>) -> Self {
+        install_before_unload_handler();
+
+        Self {
+            forms: CopyValue::new(HashMap::new()),
+            next_id: CopyValue::new(0),
+            confirm: CopyValue::new(confirm),
+            confirming: CopyValue::new(false),
+            scope_id: current_scope_id(),
+        }
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.get_dirty_count() > 0
+    }
+
+    pub fn get_dirty_count(&self) -> usize {
+        self.forms.read().values().filter(|dirty| **dirty).count()
+    }
+
+    pub async fn confirm_navigation(&self) -> bool {
+        if !self.is_dirty() {
+            return true;
+        }
+
+        let confirm = self.confirm.read().clone();
+        confirm().await
+    }
+
+    pub fn navigate(&self, navigate: impl FnOnce() + 'static) {
+        if !self.is_dirty() {
+            navigate();
+            return;
+        }
+
+        if *self.confirming.read() {
+            return;
+        }
+
+        let mut confirming = self.confirming;
+        confirming.set(true);
+
+        let guard = *self;
+
+        Runtime::current().in_scope(self.scope_id, || {
+            spawn(async move {
+                let confirmed = guard.confirm_navigation().await;
+                confirming.set(false);
+
+                if confirmed {
+                    navigate();
+                }
+            })
+        });
+    }
+
+    fn register(&self) -> u64 {
+        let mut next_id = self.next_id;
+        let id = *next_id.read() + 1;
+        next_id.set(id);
+
+        let mut forms = self.forms;
+        forms.write().insert(id, false);
+
+        id
+    }
+
+    fn set_dirty(&self, id: u64, dirty: bool) {
+        let mut forms = self.forms;
+
+        let changed = match forms.write().get_mut(&id) {
+            Some(value) => std::mem::replace(value, dirty) != dirty,
+            None => false,
+        };
+
+        if changed {
+            self.sync_dirty_count();
+        }
+    }
+
+    fn unregister(&self, id: u64) {
+        let mut forms = self.forms;
+        let was_dirty = forms.write().remove(&id).unwrap_or(false);
+
+        if was_dirty {
+            self.sync_dirty_count();
+        }
+    }
+
+    fn sync_dirty_count(&self) {
+        let js = format!(
+            "window.__dioxusUtilsDirtyForms = {};",
+            self.get_dirty_count()
+        );
+        crate::eval(&js);
+    }
+}
+
+pub fn use_unsaved_changes_guard_provider(message: &str) -> UnsavedChangesGuard {
+    let message = message.to_string();
+
+    use_unsaved_changes_guard_provider_with_confirm(move || {
+        let message = message.clone();
+        async move { confirm(message.as_str()) }
+    })
+}
+
+pub fn use_unsaved_changes_guard_provider_with_confirm<TFuture>(
+    confirm: impl Fn() -> TFuture + 'static,
+) -> UnsavedChangesGuard
+where
+    TFuture: Future<Output = bool> + 'static,
+{
+    let guard = use_context_provider(|| {
+        let confirm: Rc<ConfirmCallback> = Rc::new(move || Box::pin(confirm()));
+        UnsavedChangesGuard::new(confirm)
+    });
+
+    use_hook(|| {
+        if let Some(history) = try_consume_context::<Rc<dyn History>>() {
+            provide_history_context(Rc::new(GuardedHistory::new(history, guard)));
+        }
+    });
+
+    guard
+}
+
+pub fn use_unsaved_changes_guard() -> UnsavedChangesGuard {
+    use_context()
+}
+
+pub fn use_unsaved_changes(is_dirty: bool) -> UnsavedChangesGuard {
+    let guard = use_unsaved_changes_guard();
+    let id = use_hook(|| guard.register());
+
+    use_drop(move || guard.unregister(id));
+
+    guard.set_dirty(id, is_dirty);
+
+    guard
+}
+
+struct GuardedHistory {
+    inner: Rc<dyn History>,
+    guard: UnsavedChangesGuard,
+    router_update: Rc<RefCell<Option<RouterUpdate>>>,
+    pop_state_listener: RefCell<Option<PopStateListener>>,
+}
+
+impl GuardedHistory {
+    fn new(inner: Rc<dyn History>, guard: UnsavedChangesGuard) -> Self {
+        Self {
+            inner,
+            guard,
+            router_update: Rc::new(RefCell::new(None)),
+            pop_state_listener: RefCell::new(None),
+        }
+    }
+
+    fn navigate(&self, navigate: impl FnOnce(&dyn History) + 'static) {
+        if !self.guard.is_dirty() {
+            navigate(self.inner.as_ref());
+            return;
+        }
+
+        let inner = self.inner.clone();
+        let router_update = self.router_update.clone();
+
+        self.guard.navigate(move || {
+            navigate(inner.as_ref());
+
+            if let Some(router_update) = router_update.borrow().as_ref() {
+                router_update();
+            }
+        });
+    }
+
+    fn is_pop_state_guarded(&self) -> bool {
+        self.pop_state_listener.borrow().is_some()
+    }
+}
+
+impl History for GuardedHistory {
+    fn current_route(&self) -> String {
+        self.inner.current_route()
+    }
+
+    fn current_prefix(&self) -> Option<String> {
+        self.inner.current_prefix()
+    }
+
+    fn can_go_back(&self) -> bool {
+        self.inner.can_go_back()
+    }
+
+    fn can_go_forward(&self) -> bool {
+        self.inner.can_go_forward()
+    }
+
+    // With the popstate listener in place, back/forward is confirmed when the
+    // browser reports the step, the same way as the browser buttons.
+    fn go_back(&self) {
+        if self.is_pop_state_guarded() {
+            self.inner.go_back();
+            return;
+        }
+
+        self.navigate(|inner| inner.go_back());
+    }
+
+    fn go_forward(&self) {
+        if self.is_pop_state_guarded() {
+            self.inner.go_forward();
+            return;
+        }
+
+        self.navigate(|inner| inner.go_forward());
+    }
+
+    fn push(&self, route: String) {
+        self.navigate(move |inner| inner.push(route));
+    }
+
+    fn replace(&self, route: String) {
+        self.navigate(move |inner| inner.replace(route));
+    }
+
+    fn external(&self, url: String) -> bool {
+        if !self.guard.is_dirty() {
+            return self.inner.external(url);
+        }
+
+        let inner = self.inner.clone();
+        self.guard.navigate(move || {
+            inner.external(url);
+        });
+
+        true
+    }
+
+    fn updater(&self, callback: RouterUpdate) {
+        self.router_update.replace(Some(callback.clone()));
+
+        if !self.is_pop_state_guarded() {
+            let listener = install_pop_state_handler(self.guard);
+            self.pop_state_listener.replace(listener);
+        }
+
+        self.inner.updater(callback);
+    }
+
+    fn include_prevent_default(&self) -> bool {
+        self.inner.include_prevent_default()
+    }
+}
+
+#[cfg(all(any(feature = "web", feature = "fullstack"), not(feature = "server")))]
+struct PopStateListener {
+    window: web_sys::Window,
+    on_pop_state: js_sys::wasm_bindgen::closure::Closure<dyn FnMut(web_sys::Event)>,
+}
+
+#[cfg(all(any(feature = "web", feature = "fullstack"), not(feature = "server")))]
+impl Drop for PopStateListener {
+    fn drop(&mut self) {
+        use js_sys::wasm_bindgen::JsCast;
+
+        let _ = self.window.remove_event_listener_with_callback(
+            "popstate",
+            self.on_pop_state.as_ref().unchecked_ref(),
+        );
+    }
+}
+
+#[cfg(not(all(any(feature = "web", feature = "fullstack"), not(feature = "server"))))]
+type PopStateListener = ();
+
+// Registered before the router's own popstate listener, so a back/forward
+// step taken while forms are dirty can be stopped before the router sees it.
+// The browser has already moved by then; the step is undone with `history.go`
+// using the entry indexes stamped by `install_history_index`.
+#[cfg(all(any(feature = "web", feature = "fullstack"), not(feature = "server")))]
+fn install_pop_state_handler(guard: UnsavedChangesGuard) -> Option<PopStateListener> {
+    use js_sys::wasm_bindgen::{closure::Closure, JsCast, JsValue};
+    use std::cell::Cell;
+
+    const HISTORY_INDEX_KEY: &str = "__dioxusUtilsHistoryIndex";
+
+    let window = web_sys::window()?;
+    install_history_index();
+
+    let runtime = Runtime::current();
+    let skip_next_pop_state = Rc::new(Cell::new(false));
+    let listener_window = window.clone();
+
+    let on_pop_state = Closure::<dyn FnMut(web_sys::Event)>::new(move |event: web_sys::Event| {
+        let Ok(browser_history) = listener_window.history() else {
+            return;
+        };
+
+        let read_index = |target: &JsValue| {
+            js_sys::Reflect::get(target, &JsValue::from_str(HISTORY_INDEX_KEY))
+                .ok()
+                .and_then(|index| index.as_f64())
+        };
+
+        let target_index = browser_history
+            .state()
+            .ok()
+            .and_then(|state| read_index(&state));
+        let current_index = read_index(&listener_window);
+
+        if let Some(target_index) = target_index {
+            let _ = js_sys::Reflect::set(
+                &listener_window,
+                &JsValue::from_str(HISTORY_INDEX_KEY),
+                &JsValue::from_f64(target_index),
+            );
+        }
+
+        if skip_next_pop_state.replace(false) || !guard.is_dirty() {
+            return;
+        }
+
+        let (Some(target_index), Some(current_index)) = (target_index, current_index) else {
+            return;
+        };
+
+        let delta = (target_index - current_index) as i32;
+
+        if delta == 0 {
+            return;
+        }
+
+        event.stop_immediate_propagation();
+
+        skip_next_pop_state.set(true);
+        let _ = browser_history.go_with_delta(-delta);
+
+        let skip_next_pop_state = skip_next_pop_state.clone();
+
+        runtime.in_scope(guard.scope_id, || {
+            guard.navigate(move || {
+                skip_next_pop_state.set(true);
+                let _ = browser_history.go_with_delta(delta);
+            })
+        });
+    });
+
+    window
+        .add_event_listener_with_callback("popstate", on_pop_state.as_ref().unchecked_ref())
+        .ok()?;
+
+    Some(PopStateListener {
+        window,
+        on_pop_state,
+    })
+}
+
+#[cfg(not(all(any(feature = "web", feature = "fullstack"), not(feature = "server"))))]
+fn install_pop_state_handler(_guard: UnsavedChangesGuard) -> Option<PopStateListener> {
+    None
+}
+
+// The router keeps its own `[x, y]` scroll array in `history.state` and
+// rewrites it on every navigation, so the entry index is added by wrapping
+// `pushState` / `replaceState` rather than stored once.
+#[cfg(all(any(feature = "web", feature = "fullstack"), not(feature = "server")))]
+fn install_history_index() {
+    let js = r"
+        if (!window.__dioxusUtilsHistoryIndexInstalled) {
+            window.__dioxusUtilsHistoryIndexInstalled = true;
+
+            var history = window.history;
+            var pushState = history.pushState;
+            var replaceState = history.replaceState;
+
+            var stamp = function (state, index) {
+                var result;
+                if (Array.isArray(state)) {
+                    result = state.slice();
+                } else if (state && typeof state === 'object') {
+                    result = Object.assign({}, state);
+                } else {
+                    result = {};
+                }
+                result.__dioxusUtilsHistoryIndex = index;
+                return result;
+            };
+
+            var current = history.state && history.state.__dioxusUtilsHistoryIndex;
+            window.__dioxusUtilsHistoryIndex = typeof current === 'number' ? current : 0;
+
+            history.pushState = function (state, title, url) {
+                var index = window.__dioxusUtilsHistoryIndex + 1;
+                var result = pushState.call(history, stamp(state, index), title, url);
+                window.__dioxusUtilsHistoryIndex = index;
+                return result;
+            };
+
+            history.replaceState = function (state, title, url) {
+                return replaceState.call(
+                    history,
+                    stamp(state, window.__dioxusUtilsHistoryIndex),
+                    title,
+                    url
+                );
+            };
+
+            replaceState.call(history, stamp(history.state, window.__dioxusUtilsHistoryIndex), '');
+        }
+    ";
+
+    crate::eval(js);
+}
+
+fn install_before_unload_handler() {
+    let js = r"
+        if (!window.__dioxusUtilsBeforeUnloadInstalled) {
+            window.__dioxusUtilsBeforeUnloadInstalled = true;
+            window.__dioxusUtilsDirtyForms = 0;
+            window.addEventListener('beforeunload', function (e) {
+                if (window.__dioxusUtilsDirtyForms > 0) {
+                    e.preventDefault();
+                    e.returnValue = '';
+                }
+            });
+        }
+    ";
+
+    crate::eval(js);
+}
+
+#[cfg(not(feature = "server"))]
+fn confirm(message: &str) -> bool {
+    let js = format!(
+        "confirm('{}')",
+        crate::console_log::escape_for_java_script_string(message)
+    );
+
+    crate::eval(&js).as_bool().unwrap_or(true)
+}
+
+#[cfg(feature = "server")]
+fn confirm(_message: &str) -> bool {
+    true
+}
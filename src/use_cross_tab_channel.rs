@@ -0,0 +1,94 @@
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    rc::Rc,
+    task::{Poll, Waker},
+};
+
+use dioxus::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{js::CrossTabChannel, NotifyChildComponent};
+
+struct CrossTabQueue<T> {
+    messages: VecDeque<T>,
+    waker: Option<Waker>,
+}
+
+pub struct CrossTabSender<T: Serialize + DeserializeOwned + 'static> {
+    channel: CopyValue<CrossTabChannel<T>>,
+}
+
+impl<T: Serialize + DeserializeOwned + 'static> Clone for CrossTabSender<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Serialize + DeserializeOwned + 'static> Copy for CrossTabSender<T> {}
+
+impl<T: Serialize + DeserializeOwned + 'static> PartialEq for CrossTabSender<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.channel == other.channel
+    }
+}
+
+impl<T: Serialize + DeserializeOwned + 'static> CrossTabSender<T> {
+    pub fn post(&self, message: &T) {
+        self.channel.read().post(message);
+    }
+}
+
+pub fn use_cross_tab_channel<T: Serialize + DeserializeOwned + 'static>(
+    name: &str,
+    mut on_message: impl FnMut(T) + 'static,
+) -> CrossTabSender<T> {
+    let channel = use_hook(|| {
+        let queue = Rc::new(RefCell::new(CrossTabQueue {
+            messages: VecDeque::new(),
+            waker: None,
+        }));
+
+        let mut channel = CrossTabChannel::new(name);
+
+        let incoming = queue.clone();
+        channel.on_message(move |message| {
+            let mut incoming = incoming.borrow_mut();
+            incoming.messages.push_back(message);
+
+            if let Some(waker) = incoming.waker.take() {
+                waker.wake();
+            }
+        });
+
+        spawn(async move {
+            loop {
+                let message = std::future::poll_fn(|cx| {
+                    let mut queue = queue.borrow_mut();
+
+                    match queue.messages.pop_front() {
+                        Some(message) => Poll::Ready(message),
+                        None => {
+                            queue.waker = Some(cx.waker().clone());
+                            Poll::Pending
+                        }
+                    }
+                })
+                .await;
+
+                on_message(message);
+            }
+        });
+
+        CopyValue::new(channel)
+    });
+
+    CrossTabSender { channel }
+}
+
+pub fn use_cross_tab_notify<T: Serialize + DeserializeOwned + 'static>(
+    name: &str,
+    notify: NotifyChildComponent<T>,
+) -> CrossTabSender<T> {
+    use_cross_tab_channel(name, move |message| notify.notify_other_components(message))
+}
@@ -0,0 +1,90 @@
+use std::{fmt::Debug, future::Future};
+
+use dioxus::{core::Task, prelude::*};
+
+use crate::DataState;
+
+pub fn use_data_state<TKey, T, E, TFuture>(
+    key: TKey,
+    loader: impl FnOnce(TKey) -> TFuture,
+) -> Signal<DataState<T, E>>
+where
+    TKey: PartialEq + Clone + 'static,
+    T: Debug + 'static,
+    E: Debug + 'static,
+    TFuture: Future<Output = Result<T, E>> + 'static,
+{
+    let mut state = use_signal(DataState::<T, E>::new_typed);
+    let mut loaded_key: CopyValue<Option<TKey>> = use_hook(|| CopyValue::new(None));
+    let mut requested_generation: CopyValue<Option<u64>> = use_hook(|| CopyValue::new(None));
+    let mut load_task: CopyValue<Option<Task>> = use_hook(|| CopyValue::new(None));
+
+    let next_generation = state.peek().get_generation() + 1;
+
+    let key_changed = loaded_key.read().as_ref() != Some(&key);
+    let reload_requested =
+        state.peek().is_none() && *requested_generation.read() != Some(next_generation);
+
+    if !key_changed && !reload_requested {
+        return state;
+    }
+
+    if let Some(task) = load_task.write().take() {
+        task.cancel();
+    }
+
+    requested_generation.set(Some(next_generation));
+    loaded_key.set(Some(key.clone()));
+
+    let future = loader(key);
+
+    let task = spawn(async move {
+        let generation = state.write().start_loading();
+
+        match future.await {
+            Ok(value) => state.write().set_loaded_if_current(generation, value),
+            Err(err) => state.write().set_typed_error_if_current(generation, err),
+        };
+    });
+
+    load_task.set(Some(task));
+
+    state
+}
+
+#[cfg(any(feature = "fullstack", feature = "web"))]
+pub fn use_auto_invalidate<T: Debug + 'static, E: Debug + 'static>(
+    mut state: Signal<DataState<T, E>>,
+) {
+    let mut expire_task: CopyValue<Option<Task>> = use_hook(|| CopyValue::new(None));
+
+    use_effect(move || {
+        let time_to_expire = state.read().get_time_to_expire();
+
+        if let Some(task) = expire_task.write().take() {
+            task.cancel();
+        }
+
+        let Some(mut delay) = time_to_expire else {
+            return;
+        };
+
+        let task = spawn(async move {
+            loop {
+                crate::js::sleep(delay).await;
+
+                if state.peek().is_expired() {
+                    state.write().reset();
+                    return;
+                }
+
+                match state.peek().get_time_to_expire() {
+                    Some(time_to_expire) => delay = time_to_expire,
+                    None => return,
+                }
+            }
+        });
+
+        expire_task.set(Some(task));
+    });
+}